@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+/// Exponential backoff between retries of a failed request
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Backoff {
+    /// Delay before the first retry
+    pub initial: Duration,
+
+    /// The delay doubles after every retry until it reaches `max`
+    pub max: Duration,
+
+    /// Number of retries before giving up, `None` to retry forever
+    pub retries: Option<u32>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(500),
+            max: Duration::from_secs(30),
+            retries: Some(5),
+        }
+    }
+}
+
+impl Backoff {
+    /// The default delays, but retrying forever
+    pub fn forever() -> Self {
+        Self {
+            retries: None,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retry number `attempt` (starting at 0),
+    /// `None` when there should be no more retries
+    pub(crate) fn delay(&self, attempt: u32) -> Option<Duration> {
+        if self.retries.is_some_and(|retries| attempt >= retries) {
+            return None;
+        }
+
+        let factor = 2u32.saturating_pow(attempt);
+        Some(self.initial.saturating_mul(factor).min(self.max))
+    }
+
+    /// The delays to wait before each retry
+    pub(crate) fn delays(&self) -> impl Iterator<Item = Duration> + '_ {
+        (0..).map_while(|attempt| self.delay(attempt))
+    }
+}
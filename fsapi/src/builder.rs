@@ -0,0 +1,83 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::{Error, FsApi};
+
+/// Time to wait for the tcp connection to the radio
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time to wait for a complete response
+///
+/// Must be longer than the time the radio holds a `GET_NOTIFIES` request open
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Number of items requested per `LIST_GET_NEXT`
+const DEFAULT_LIST_PAGE_SIZE: u32 = 50;
+
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Builder for an [`FsApi`] client
+#[derive(Debug)]
+pub struct FsApiBuilder {
+    host: String,
+    pin: u32,
+    connect_timeout: Duration,
+    timeout: Duration,
+    user_agent: String,
+    list_page_size: u32,
+}
+
+impl FsApiBuilder {
+    pub(crate) fn new<D: Display>(host: D, pin: u32) -> Self {
+        Self {
+            host: host.to_string(),
+            pin,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            timeout: DEFAULT_TIMEOUT,
+            user_agent: String::from(DEFAULT_USER_AGENT),
+            list_page_size: DEFAULT_LIST_PAGE_SIZE,
+        }
+    }
+
+    /// Time to wait for the connection to the radio (default 5s)
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Time to wait for a complete response (default 60s)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// User agent sent with every request
+    pub fn user_agent<T: ToString>(mut self, user_agent: T) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Number of items requested per page when listing a node (default 50)
+    ///
+    /// Big pages are truncated or time out on some firmware.
+    pub fn list_page_size(mut self, size: u32) -> Self {
+        self.list_page_size = size.max(1);
+        self
+    }
+
+    pub fn build(self) -> Result<FsApi, Error> {
+        let client = reqwest::Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.timeout)
+            .user_agent(self.user_agent)
+            .build()
+            .map_err(|err| Error::InternalError(Box::new(err)))?;
+
+        Ok(FsApi {
+            host: self.host,
+            pin: self.pin,
+            client,
+            list_page_size: self.list_page_size,
+        })
+    }
+}
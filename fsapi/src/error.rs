@@ -1,69 +1,237 @@
 use std::fmt;
 
+pub use context::{ErrorContext, Operation};
 pub(crate) use internal::InternalError;
 
+use crate::{Node, ValueKind};
+
+mod context;
 mod internal;
 
 //pub type Result<T> = std::result::Result<T, Error>;
 
-#[derive(Debug, PartialEq)]
+/// Boxed error used as the [`source`](std::error::Error::source) of an [`Error`]
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug)]
 pub enum Error {
     /// Wrong pin (403)
-    WrongPin,
-
-    /// An Response data type was returned
-    InvalidStatus,
+    WrongPin(ErrorContext),
 
     /// Got an FS_FAIL
-    /// usually means that the action requested was not available
-    /// for the current mode
-    Fail,
+    /// usually means that the value does not match the validation rules
+    /// or the action requested was not available for the current mode
+    Fail(ErrorContext),
+
+    /// Got an FS_PACKET_BAD
+    /// usually means that a read only node was `SET`
+    PacketBad(ErrorContext),
+
+    /// Got an FS_NODE_BLOCKED
+    /// the node belongs to an operation mode which is not active
+    NodeBlocked(ErrorContext),
+
+    /// Got an FS_NODE_DOES_NOT_EXIST
+    NodeDoesNotExist(ErrorContext),
+
+    /// Got an FS_TIMEOUT, the request took to long on the radio
+    Timeout(ErrorContext),
+
+    /// Got an FS_LIST_END where no list was requested
+    ListEnd(ErrorContext),
+
+    /// A page of a list did not start after the previous one,
+    /// the radio ignored where the page should start
+    ListStalled(ErrorContext),
+
+    /// Got a status this crate does not know
+    UnknownStatus {
+        status: String,
+        context: ErrorContext,
+    },
 
     /// An invalid data type was returned
-    InvalidData,
+    InvalidData(ErrorContext),
 
-    /// Something went wrong
-    InternalError,
+    /// The response of the radio could not be parsed
+    InvalidResponse {
+        context: ErrorContext,
+
+        /// The raw response as sent by the radio
+        payload: String,
+
+        /// What went wrong while parsing
+        source: BoxError,
+    },
 
-    /// Server to to long to repond
-    Timeout,
+    /// A value of another type than requested was returned
+    UnexpectedValue {
+        expected: ValueKind,
+        found: ValueKind,
+
+        /// The request that returned the value, `None` when converting a [`Value`](crate::Value)
+        context: Option<ErrorContext>,
+    },
+
+    /// The node can not be read with `GET`
+    NotReadable(Node),
+
+    /// The node can not be changed with `SET`
+    ReadOnly(Node),
+
+    /// The node is not a list
+    NotListable(Node),
+
+    /// The value is outside of the range accepted by the node
+    OutOfRange { node: Node, value: i64 },
+
+    /// The request could not be sent or the server did not respond (in time)
+    Http {
+        context: ErrorContext,
+        source: reqwest::Error,
+    },
+
+    /// A download was bigger than allowed, see [`FsApi::download`](crate::FsApi::download)
+    TooLarge {
+        context: ErrorContext,
+        max_size: u64,
+    },
+
+    /// The [`SessionManager`](crate::SessionManager) was shut down
+    SessionClosed,
+
+    /// Something went wrong
+    InternalError(BoxError),
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl Error {
+    /// Converts an error of the http client, a 403 means the pin was wrong
+    pub(crate) fn http(err: reqwest::Error, mut context: ErrorContext) -> Self {
+        context.http_status = context.http_status.or(err.status());
+
+        match err.status() {
+            Some(reqwest::StatusCode::FORBIDDEN) => Self::WrongPin(context),
+            _ => Self::Http {
+                context,
+                source: err,
+            },
+        }
+    }
+
+    /// The request during which the error happened, if it got that far
+    pub fn context(&self) -> Option<&ErrorContext> {
         match self {
-            Error::WrongPin => write!(f, "Looks like you got the pin wrong"),
-            Error::InvalidStatus => write!(f, "An invalid status type was returned"),
-            Error::Fail => write!(f, "That action is not possible (I think)"),
-            Error::InvalidData => write!(f, "An invalid data type was returned"),
-            Error::InternalError => write!(f, "Oops something went wrong"),
-            Error::Timeout => write!(f, "Server took to long to respond"),
+            Error::WrongPin(context)
+            | Error::Fail(context)
+            | Error::PacketBad(context)
+            | Error::NodeBlocked(context)
+            | Error::NodeDoesNotExist(context)
+            | Error::Timeout(context)
+            | Error::ListEnd(context)
+            | Error::ListStalled(context)
+            | Error::UnknownStatus { context, .. }
+            | Error::InvalidData(context)
+            | Error::InvalidResponse { context, .. }
+            | Error::Http { context, .. }
+            | Error::TooLarge { context, .. } => Some(context),
+            Error::UnexpectedValue { context, .. } => context.as_ref(),
+            Error::NotReadable(_)
+            | Error::ReadOnly(_)
+            | Error::NotListable(_)
+            | Error::OutOfRange { .. }
+            | Error::SessionClosed
+            | Error::InternalError(_) => None,
         }
     }
-}
 
-impl std::error::Error for Error {}
+    /// Whether the radio no longer knows the session of the request
+    ///
+    /// This happens when another client created a new session.
+    pub fn is_invalid_session(&self) -> bool {
+        let http_status = self.context().and_then(|context| context.http_status);
+        http_status == Some(reqwest::StatusCode::NOT_FOUND)
+    }
 
-impl From<InternalError> for Error {
-    fn from(_: InternalError) -> Self {
-        Self::InternalError
+    /// Whether the radio or the http client gave up waiting
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Error::Timeout(_) => true,
+            Error::Http { source, .. } => source.is_timeout(),
+            _ => false,
+        }
     }
 }
 
-impl From<reqwest::Error> for Error {
-    fn from(err: reqwest::Error) -> Self {
-        dbg!(&err);
-        if err.is_status() {
-            use reqwest::StatusCode;
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::WrongPin(context) => write!(f, "Looks like you got the pin wrong ({context})"),
+            Error::Fail(context) => write!(f, "FS_FAIL on {context}: the action is not possible"),
+            Error::PacketBad(context) => {
+                write!(f, "FS_PACKET_BAD on {context}: the node is read only")
+            }
+            Error::NodeBlocked(context) => write!(
+                f,
+                "FS_NODE_BLOCKED on {context}: the node is not available in the current mode"
+            ),
+            Error::NodeDoesNotExist(context) => {
+                write!(f, "FS_NODE_DOES_NOT_EXIST on {context}")
+            }
+            Error::Timeout(context) => write!(f, "FS_TIMEOUT on {context}"),
+            Error::ListEnd(context) => write!(f, "FS_LIST_END on {context}"),
+            Error::ListStalled(context) => {
+                write!(f, "The list did not advance on {context}")
+            }
+            Error::UnknownStatus { status, context } => {
+                write!(f, "Unknown status {status} on {context}")
+            }
+            Error::InvalidData(context) => {
+                write!(f, "An invalid data type was returned on {context}")
+            }
+            Error::InvalidResponse {
+                context,
+                payload,
+                source,
+            } => write!(f, "Invalid response on {context} ({source}): {payload}"),
+            Error::UnexpectedValue {
+                expected,
+                found,
+                context: Some(context),
+            } => write!(
+                f,
+                "Expected a value of type {expected} on {context}, got {found}"
+            ),
+            Error::UnexpectedValue {
+                expected, found, ..
+            } => write!(f, "Expected a value of type {expected}, got {found}"),
+            Error::NotReadable(node) => write!(f, "{node} can not be read"),
+            Error::ReadOnly(node) => write!(f, "{node} is read only"),
+            Error::NotListable(node) => write!(f, "{node} is not a list"),
+            Error::OutOfRange { node, value } => write!(f, "{value} is out of range for {node}"),
+            Error::Http { context, source } => write!(f, "Request {context} failed: {source}"),
+            Error::TooLarge { context, max_size } => {
+                write!(f, "{context} is larger than {max_size} bytes")
+            }
+            Error::SessionClosed => write!(f, "The session was closed"),
+            Error::InternalError(source) => write!(f, "Oops something went wrong: {source}"),
+        }
+    }
+}
 
-            match err.status() {
-                Some(StatusCode::FORBIDDEN) => Self::WrongPin,
-                _ => Self::InternalError,
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidResponse { source, .. } | Error::InternalError(source) => {
+                Some(source.as_ref())
             }
-        } else if err.is_timeout() {
-            Self::Timeout
-        } else {
-            Self::InternalError
+            Error::Http { source, .. } => Some(source),
+            _ => None,
         }
     }
 }
+
+impl From<InternalError> for Error {
+    fn from(err: InternalError) -> Self {
+        Self::InternalError(Box::new(err))
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use reqwest::StatusCode;
+
+use crate::Node;
+
+/// The kind of request that was made to the fsapi server
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Get,
+    Set,
+    GetMultiple,
+    SetMultiple,
+    ListGetNext,
+    GetNotifies,
+    CreateSession,
+    DeleteSession,
+
+    /// A file that is not part of the fsapi, see [`FsApi::download`](crate::FsApi::download)
+    Download,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operation = match self {
+            Operation::Get => "GET",
+            Operation::Set => "SET",
+            Operation::GetMultiple => "GET_MULTIPLE",
+            Operation::SetMultiple => "SET_MULTIPLE",
+            Operation::ListGetNext => "LIST_GET_NEXT",
+            Operation::GetNotifies => "GET_NOTIFIES",
+            Operation::CreateSession => "CREATE_SESSION",
+            Operation::DeleteSession => "DELETE_SESSION",
+            Operation::Download => "DOWNLOAD",
+        };
+        write!(f, "{operation}")
+    }
+}
+
+/// The request during which an [`Error`](super::Error) happened
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub operation: Operation,
+
+    /// The node the request was about, if any
+    pub node: Option<Node>,
+
+    /// HTTP status of the response, `None` if no response was received
+    pub http_status: Option<StatusCode>,
+}
+
+impl ErrorContext {
+    pub(crate) fn new(operation: Operation, node: Option<&Node>) -> Self {
+        Self {
+            operation,
+            node: node.cloned(),
+            http_status: None,
+        }
+    }
+
+    /// The same context for one node of a multiple request
+    pub(crate) fn with_node(&self, node: &Node) -> Self {
+        Self {
+            node: Some(node.clone()),
+            ..self.clone()
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)?;
+        if let Some(node) = &self.node {
+            write!(f, " {node}")?;
+        }
+        if let Some(status) = self.http_status {
+            write!(f, " (HTTP {})", status.as_u16())?;
+        }
+        Ok(())
+    }
+}
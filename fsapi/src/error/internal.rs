@@ -20,6 +20,9 @@ pub(crate) enum InternalError {
 
     Field(String),
 
+    /// The xml of the response is malformed
+    Xml(quick_xml::Error),
+
     /// Just a generic error without dedicated variant,
     /// with a string to store a description
     Generic(String),
@@ -43,13 +46,21 @@ impl fmt::Display for InternalError {
             InternalError::Response(s) => write!(f, "Error response: {s}"),
             InternalError::Item(s) => write!(f, "Error item: {s}"),
             InternalError::Field(s) => write!(f, "Error field: {s}"),
+            InternalError::Xml(err) => write!(f, "Error xml: {err}"),
             InternalError::Generic(desc) => write!(f, "Error: {desc}"),
             InternalError::Empty => write!(f, "Error"),
         }
     }
 }
 
-impl std::error::Error for InternalError {}
+impl std::error::Error for InternalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InternalError::Xml(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl From<Error> for InternalError {
     fn from(_: Error) -> Self {
@@ -58,7 +69,7 @@ impl From<Error> for InternalError {
 }
 
 quick_impl!(From<reqwest::Error> for InternalError);
-quick_impl!(From<quick_xml::Error> for InternalError);
-quick_impl!(From<AttrError> for InternalError);
+quick_impl!(From<quick_xml::Error> for InternalError, InternalError::Xml);
+quick_impl!(From<AttrError> for InternalError, InternalError::Xml);
 quick_impl!(From<std::num::ParseIntError> for InternalError);
 quick_impl!(From<std::string::FromUtf8Error> for InternalError);
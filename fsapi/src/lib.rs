@@ -1,14 +1,38 @@
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
 use quick_xml::{events::Event, name::QName};
+use std::collections::HashMap;
 use std::fmt::Display;
 
-pub use error::Error;
+pub use backoff::Backoff;
+pub use builder::FsApiBuilder;
 use error::InternalError;
+pub use error::{BoxError, Error, ErrorContext, Operation};
+pub use node::{Node, NodeDescriptor, TypedNode, WritableNode};
+pub use session::SessionManager;
+pub use value::{Value, ValueKind};
 
+mod backoff;
+mod builder;
 mod error;
 mod macros;
-
-#[derive(Debug)]
-pub struct FsApi();
+pub mod node;
+mod notifications;
+mod session;
+mod value;
+
+/// Client for the fsapi server of a radio
+///
+/// Holds the connection details and a connection pool, cloning is cheap
+/// and every clone shares the same keep-alive connection.
+#[derive(Clone, Debug)]
+pub struct FsApi {
+    host: String,
+    pin: u32,
+    client: reqwest::Client,
+
+    /// Number of items requested per `LIST_GET_NEXT`
+    list_page_size: u32,
+}
 
 const FSAPI_PATH: &str = "fsapi";
 const GET_PATH: &str = "GET";
@@ -17,6 +41,21 @@ const LIST_GET_NEXT_PATH: &str = "LIST_GET_NEXT";
 const CREATE_SESSION_PATH: &str = "CREATE_SESSION";
 const DELETE_SESSION_PATH: &str = "DELETE_SESSION";
 const GET_NOTIFIES_PATH: &str = "GET_NOTIFIES";
+const GET_MULTIPLE_PATH: &str = "GET_MULTIPLE";
+const SET_MULTIPLE_PATH: &str = "SET_MULTIPLE";
+
+/// Percent-encodes a value for the query string, text values may contain `&` or spaces
+fn encode_query(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
 
 /// Response of the fsapi server
 #[derive(Debug, PartialEq)]
@@ -37,7 +76,7 @@ const STATUS_FS_TIMEOUT: &str = "FS_TIMEOUT";
 const STATUS_FS_LIST_END: &str = "FS_LIST_END";
 
 /// Resposn status of the fsapi server
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 enum ResponseStatus {
     /// Everything went well: The command has been executed.
     Ok,
@@ -59,6 +98,42 @@ enum ResponseStatus {
 
     /// There is no list-entry left.
     ListEnd,
+
+    /// A status this crate does not know
+    Unknown(String),
+}
+
+impl ResponseStatus {
+    fn parse(status: &str) -> Self {
+        use ResponseStatus::*;
+        match status {
+            STATUS_FS_OK => Ok,
+            STATUS_FS_FAIL => Fail,
+            STATUS_FS_PACKET_BAD => PacketBad,
+            STATUS_FS_NODE_BLOCKED => NodeBlocked,
+            STATUS_FS_NODE_DOES_NOT_EXIST => NodeDoesNotExist,
+            STATUS_FS_TIMEOUT => Timeout,
+            STATUS_FS_LIST_END => ListEnd,
+            status => Unknown(status.to_string()),
+        }
+    }
+
+    /// Turns every status other than `FS_OK` into the matching error
+    fn check(self, context: &ErrorContext) -> Result<(), Error> {
+        let context = context.clone();
+        let err = match self {
+            ResponseStatus::Ok => return Ok(()),
+            ResponseStatus::Fail => Error::Fail(context),
+            ResponseStatus::PacketBad => Error::PacketBad(context),
+            ResponseStatus::NodeBlocked => Error::NodeBlocked(context),
+            ResponseStatus::NodeDoesNotExist => Error::NodeDoesNotExist(context),
+            ResponseStatus::Timeout => Error::Timeout(context),
+            ResponseStatus::ListEnd => Error::ListEnd(context),
+            ResponseStatus::Unknown(status) => Error::UnknownStatus { status, context },
+        };
+
+        Err(err)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -67,19 +142,34 @@ enum Data {
     Value(Value),
 
     /// Result from `fsapi/LIST_GET_NEXT`
-    Items(Vec<Item>),
+    ///
+    /// `list_end` is set when this page holds the last item of the list
+    Items { items: Vec<Item>, list_end: bool },
 
     /// Session id response on `fsapi/CREATE_SESSION`
     SessionID(SessionID),
 
     /// resonse on `/fsapi/NOTIFY`
     Notify(Vec<Notification>),
+
+    /// Result from `fsapi/GET_MULTIPLE` and `fsapi/SET_MULTIPLE`
+    Multiple(Vec<NodeResponse>),
+}
+
+/// Response for a single node inside a `GET_MULTIPLE` or `SET_MULTIPLE` response
+#[derive(Debug, PartialEq)]
+struct NodeResponse {
+    pub node: Node,
+    pub status: ResponseStatus,
+
+    /// Only set for a successful `GET_MULTIPLE`
+    pub value: Option<Value>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct SessionID(u32);
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Notification {
     pub node: Node,
     pub value: Value,
@@ -97,1275 +187,489 @@ pub struct Field {
     pub value: Value,
 }
 
-#[derive(Debug, PartialEq)]
-pub enum Value {
-    Text(String),
-    U8(u8),
-    S16(i16),
-    U32(u32),
-    Array(String),
-}
-
-impl Display for Value {
+impl std::fmt::Display for SessionID {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Value::Text(v) => write!(f, "{v}"),
-            Value::U8(v) => write!(f, "{v}"),
-            Value::S16(v) => write!(f, "{v}"),
-            Value::U32(v) => write!(f, "{v}"),
-            Value::Array(v) => write!(f, "{v}"),
-        }
+        write!(f, "{}", self.0)
     }
 }
 
-// nav
-const NODE_NAV_LIST: &str = "netremote.nav.list";
-const NODE_NAV_NUMITEMS: &str = "netremote.nav.numItems";
-const NODE_NAV_PRESETS: &str = "netremote.nav.presets";
-const NODE_NAV_SEARCHTERM: &str = "netremote.nav.searchTerm";
-const NODE_NAV_STATE: &str = "netremote.nav.state";
-const NODE_NAV_STATUS: &str = "netremote.nav.status";
-const NODE_NAV_DEPTH: &str = "netremote.nav.depth";
-
-// nav.action
-const NODE_NAV_ACTION_DABSCAN: &str = "netremote.nav.action.dabScan";
-const NODE_NAV_ACTION_NAVIGATE: &str = "netremote.nav.action.navigate";
-const NODE_NAV_ACTION_SELECTITEM: &str = "netremote.nav.action.selectItem";
-const NODE_NAV_ACTION_SELECTPRESET: &str = "netremote.nav.action.selectPreset";
-
-// play
-const NODE_PLAY_ADDPRESET: &str = "netremote.play.addpreset";
-const NODE_PLAY_CAPS: &str = "netremote.play.caps";
-const NODE_PLAY_CONTROL: &str = "netremote.play.control";
-const NODE_PLAY_ERRORSTR: &str = "netremote.play.errorstr";
-const NODE_PLAY_FREQUENCY: &str = "netremote.play.frequency";
-const NODE_PLAY_POSITION: &str = "netremote.play.position";
-const NODE_PLAY_RATE: &str = "netremote.play.rate";
-const NODE_PLAY_REPEAT: &str = "netremote.play.repeat";
-const NODE_PLAY_SCROBBLE: &str = "netremote.play.scrobble";
-const NODE_PLAY_SHUFFLE: &str = "netremote.play.shuffle";
-const NODE_PLAY_SHUFFLESTATUS: &str = "netremote.play.shufflestatus";
-const NODE_PLAY_SIGNALSTRENGTH: &str = "netremote.play.signalstrength";
-const NODE_PLAY_STATUS: &str = "netremote.play.status";
-
-// play.info
-const NODE_PLAY_INFO_ALBUM: &str = "netremote.play.info.album";
-const NODE_PLAY_INFO_ARTIST: &str = "netremote.play.info.artist";
-const NODE_PLAY_INFO_DURATION: &str = "netremote.play.info.duration";
-const NODE_PLAY_INFO_GRAPHICURI: &str = "netremote.play.info.graphicuri";
-const NODE_PLAY_INFO_NAME: &str = "netremote.play.info.name";
-const NODE_PLAY_INFO_TEXT: &str = "netremote.play.info.text";
-
-// play.serviceIds
-const NODE_PLAY_SERVICEIDS_DABENSEMBLEID: &str = "netremote.play.serviceids.dabinsembleid";
-const NODE_PLAY_SERVICEIDS_DABSCIDS: &str = "netremote.play.serviceids.dabscids";
-const NODE_PLAY_SERVICEIDS_DABSERVICEID: &str = "netremote.play.serviceids.dabserviceid";
-const NODE_PLAY_SERVICEIDS_ECC: &str = "netremote.play.serviceids.ecc";
-const NODE_PLAY_SERVICEIDS_FMRDSPI: &str = "netremote.play.serviceids.fmrdspi";
-
-// sys
-const NODE_SYS_LANG: &str = "netremote.sys.lang";
-const NODE_SYS_MODE: &str = "netremote.sys.mode";
-const NODE_SYS_POWER: &str = "netremote.sys.power";
-const NODE_SYS_SLEEP: &str = "netremote.sys.sleep";
-const NODE_SYS_STATE: &str = "netremote.sys.state";
-
-// sys.audio
-const NODE_SYS_AUDIO_EQCUSTOM_PARAM0: &str = "netremote.sys.audio.eqcustom.param0";
-const NODE_SYS_AUDIO_EQCUSTOM_PARAM1: &str = "netremote.sys.audio.eqcustom.param1";
-
-const NODE_SYS_AUDIO_EQLOUDNESS: &str = "netremote.sys.audio.eqloudness";
-const NODE_SYS_AUDIO_EQPRESET: &str = "netremote.sys.audio.eqpreset";
-const NODE_SYS_AUDIO_VOLUME: &str = "netremote.sys.audio.volume";
-const NODE_SYS_AUDIO_MUTE: &str = "netremote.sys.audio.mute";
-
-// sys.caps
-const NODE_SYS_CAPS_CLOCKSOURCELIST: &str = "netremote.sys.caps.clocksourcelist";
-const NODE_SYS_CAPS_DABFREQLIST: &str = "netremote.sys.caps.dabfreqlist";
-const NODE_SYS_CAPS_EQBANDS: &str = "netremote.sys.caps.eqbands";
-const NODE_SYS_CAPS_EQPRESETS: &str = "netremote.sys.caps.eqpresets";
-const NODE_SYS_CAPS_VALIDMODES: &str = "netremote.sys.caps.validmodes";
-
-const NODE_SYS_CAPS_FMFREQRANGE_LOWER: &str = "netremote.sys.caps.fmfreqrange.lower";
-const NODE_SYS_CAPS_FMFREQRANGE_STEPSIZE: &str = "netremote.sys.caps.fmfreqrange.stepsize";
-const NODE_SYS_CAPS_FMFREQRANGE_UPPER: &str = "netremote.sys.caps.fmfreqrange.upper";
-
-const NODE_SYS_CAPS_VOLUMESTEPS: &str = "netremote.sys.caps.volumesteps";
-
-// sys.clock
-const NODE_SYS_CLOCK_DST: &str = "netremote.sys.clock.dst";
-const NODE_SYS_CLOCK_LOCALDATE: &str = "netremote.sys.clock.localdate";
-const NODE_SYS_CLOCK_LOCALTIME: &str = "netremote.sys.clock.localtime";
-const NODE_SYS_CLOCK_MODE: &str = "netremote.sys.clock.mode";
-const NODE_SYS_CLOCK_SOURCE: &str = "netremote.sys.clock.source";
-const NODE_SYS_CLOCK_UTCOFFSET: &str = "netremote.sys.clock.utcoffset";
-
-// sys.cfg
-const NODE_SYS_CFG_IRAUTOPLAYFLAG: &str = "netremote.sys.clock.irautoplayflag";
-
-// sys.info
-const NODE_INFO_FRIENDLYNAME: &str = "netremote.sys.info.friendlyname";
-const NODE_INFO_RADIOID: &str = "netremote.sys.info.radioid";
-const NODE_INFO_RADIOPIN: &str = "netremote.sys.info.radiopin";
-const NODE_INFO_VERSION: &str = "netremote.sys.info.version";
-const NODE_INFO_CONTROLLERNAME: &str = "netremote.sys.info.controllername";
-
-// sys.isu
-const NODE_ISU_CONTROL: &str = "netremote.sys.isu.control";
-const NODE_ISU_STATE: &str = "netremote.sys.isu.state";
-
-// sys.net
-const NODE_NET_IPCONFIG_ADDRESS: &str = "netremote.net.ipconfig.address";
-const NODE_NET_IPCONFIG_DHCP: &str = "netremote.net.ipconfig.dhcp";
-const NODE_NET_IPCONFIG_DNSPRIMARY: &str = "netremote.net.ipconfig.dnsprimary";
-const NODE_NET_IPCONFIG_DNSECUNDARY: &str = "netremote.net.ipconfig.dnssecundary";
-const NODE_NET_IPCONFIG_GATEWAY: &str = "netremote.net.ipconfig.gateway";
-const NODE_NET_IPCONFIG_SUBNETMASK: &str = "netremote.net.ipconfig.subnetmask";
-const NODE_NET_IPCONFIG_KEEPCONNECTED: &str = "netremote.net.ipconfig.keepconnected";
-
-const NODE_NET_WIRED_INTERFACEENABLE: &str = "netremote.net.wired.interfaceenable";
-const NODE_NET_WIRED_MACADDRESS: &str = "netremote.net.wired.macaddress";
-
-const NODE_NET_WLAN_CONNECTEDSSID: &str = "netremote.net.wlan.connectedssid";
-const NODE_NET_WLAN_INTERFACEENABLE: &str = "netremote.net.wlan.interfaceenable";
-const NODE_NET_WLAN_MACADDRESS: &str = "netremote.net.wlan.macaddress";
-const NODE_NET_WLAN_RSSI: &str = "netremote.net.wlan.rsii";
-const NODE_NET_WLAN_SETAUTHTYPE: &str = "netremote.net.wlan.setauthtype";
-const NODE_NET_WLAN_SETENCTYPE: &str = "netremote.net.wlan.setenctype";
-
-// sys.rsa
-const NODE_SYS_RSA_PUBLICKEY: &str = "netremote.sys.rsa.publickey";
-const NODE_SYS_RSA_STATUS: &str = "netremote.sys.rsa.status";
-
-// Airplay
-
-#[derive(Debug, PartialEq)]
-pub enum Node {
-    // nav
-    /// Get the menu for the current mode
-    ///
-    /// To prevent overhead you could get the number of items by NavNumItems
-    ///
-    /// The -1 Parameter is the Start-Value, you could provide 4 to get all items with an index greater 4.
-    ///
-    /// The following field types are known:
-    ///
-    ///  1. folder
-    ///  2. mp3
-    ///  3. mp4
-    ///
-    /// Method: LIST_GET_NEXT
-    /// Returns: `Vec<Item(_)>`
-    /// PATH: "netRemote.nav.list"
-    NavList,
-
-    /// Get the amount of entries for the current navigation-set.
-    ///
-    /// Method: GET
-    /// Returns: `Value::S32(_)`
-    /// PATH: "netRemote.nav.numItems"
-    NavNumItems,
-
-    /// Lists all favorite Radio Stations for the current mode
-    ///
-    /// Method: LIST_GET_NEXT
-    /// Returns: `Vec<Item<_>>`
-    /// PATH: "netRemote.nav.presets"
-    NavPresets,
-
-    /// Search in the current navigation (see NavList)
-    ///
-    /// Method: LIST_GET_NEXT
-    /// Returns: `Vec<Item<_>>`
-    /// PATH: "netRemote.nav.searchTerm"
-    NavSearchTerm,
-
-    /// Enables the navigation in the menu (see NavList)
-    ///
-    /// Every change of the system mode, will disable the nav state to reset the current menu-position.
-    ///
-    /// Method: GET
-    /// Returns: `Value::U8(_)`
-    /// PATH: "netRemote.nav.state"
-    NavState,
-
-    /// While the device prepares the menu this node is set to 0, if the menu is ready it is set to 1.
-    ///
-    /// Busy menus are caused by mode-change or NavActionNavigate
-    ///
-    /// Method: GET
-    /// Returns: `Value::U8(_)`
-    /// PATH: "netRemote.nav.status"
-    NavStatus,
-
-    /// Returns the current navigation menu depth
-    ///
-    /// Method: GET
-    /// Returns: `Value::U8(_)`
-    /// PATH: "netRemote.nav.depth"
-    NavDepth,
-
-    // nav.action
-    /// Starts Scan for DAB Channels
-    ///
-    /// Method: GET
-    /// Returns: `Value::S32(_)`
-    /// PATH: "netRemote.nav.action.dabScan"
-    NavActionDabScan,
-
-    /// Selects the current menu entry (see NavList)
-    ///
-    /// For returning to the upper menu level just set it to -1.
-    ///
-    /// This function works only with folders (type=0), see NavList.
-    ///
-    /// Method: GET?, SET
-    /// Returns: ?
-    /// PATH: "netRemote.nav.action.navigate"
-    NavActionNavigate,
-
-    /// Selects an Menu Item (see netRemove.nav.list)
-    ///
-    /// This function works only on files (type > 0), see NavList.
-    ///
-    /// Method: GET?, SET
-    /// Returns: ?
-    /// PATH: "netRemote.nav.action.selectItem"
-    NavActionSelectItem,
-
-    /// Selects a  favorite Radio Stations (see NavPresets)
-    ///
-    /// Method: GET?, SET
-    /// Returns: ?
-    /// PATH: "netRemote.nav.action.selectPreset"
-    NavActionSelectPreset,
-
-    // play
-    /// Add the current radio stations to the favorites menu
-    ///
-    /// Method: GET?, SET
-    /// Returns: ?
-    /// PATH: "netRemote.play.addPreset"
-    PlayAddPreset,
-
-    /// Actions the player can do
-    /// 0: PlayControl: Play?
-    /// 1: PlayControl: Pause or Play?
-    /// 2: PlayControl: Next or Previous
-    /// 3: PlayControl: Next or Previous
-    /// 4:
-    /// 5:
-    /// 6:
-    /// 7:
-    /// 8:
-    /// 9:
-    /// 10:
-    /// 11: PlayAddPreset
-    ///
-    /// Method: GET
-    /// Returns: Value::U32(_),
-    /// PATH: "netRemote.play.caps"
-    PlayCaps,
-
-    /// Sets / Return the current play-controll mode
-    ///
-    /// 0: play/pause toggle
-    /// 1: ?
-    /// 2: ?
-    /// 3: next (song/station)
-    /// 4: previous (song/station)
-    /// ...: ?
-    ///
-    /// Wrong: Scope: Mediaplayer / Radio
-    /// Wrong: 1=Play; 2=Pause; 3=Next (song/station); 4=Previous (song/station)
-    ///
-    /// Method: GET/SET
-    /// Returns: Value::U8(_),
-    /// PATH: "netRemote.play.control"
-    PlayControl,
-
-    /// Method: GET
-    /// Returns: Value::Text(_),
-    /// PATH: "netRemote.play.errorStr"
-    PlayErrorStr,
-
-    /// Sets / Returns the current frequency for fm (in herz)
-    ///
-    /// Method: GET/SET
-    /// Returns: Value::U32(_),
-    /// PATH: "netRemote.play.frequency"
-    PlayFrequency,
-
-    /// Sets / Returns the current position in the track in milliseconds
-    ///
-    /// Keep in mind to get the max position by PlayDuration
-    ///
-    /// Method: GET/SET
-    /// Returns: Value::U32(_),
-    /// PATH: "netRemote.play.position"
-    PlayPosition,
-
-    /// Sets / Returns the current play-rate multiplier
-    ///
-    /// The value determines the speed of the playback.
-    /// It can be in the range of -127 to 127 where the negative part plays the current track backwards.
-    ///
-    /// Method: GET/SET
-    /// Returns: Value::S8(_),
-    /// PATH: "netRemote.play.rate"
-    PlayRate,
-
-    /// Sets / Returns the current play-rate multiplier
-    ///
-    /// Method: GET/SET
-    /// Returns: Value::U8([0,1]),
-    /// PATH: "netRemote.play.repeat"
-    PlayRepeat,
-
-    /// Returns  whether or not scrobble is enabled or not
-    ///
-    /// Method: GET/SET
-    /// Returns: Value::U8([0,1]),
-    /// PATH: "netRemote.play.scrobble"
-    PlayScrobble,
-
-    /// Sets / Returns whether or not shuffle is enabled or not (1/0)
-    ///
-    /// Method: GET/SET
-    /// Returns: Value::U8([0,1]),
-    /// PATH: "netRemote.play.shuffle"
-    PlayShuffle,
-
-    /// Sets / Returns whether or not shuffle is enabled or not (1/0)
-    ///
-    /// Is part of the new api version
-    ///
-    /// Method: GET/SET
-    /// Returns: Value::U8([0,1]),
-    /// PATH: "netRemote.play.shuffleStatus"
-    PlayShuffleStatus,
-
-    /// Returns the signal strenght of the current medium
-    ///
-    /// Method: GET
-    /// Returns: Value::U8(_),
-    /// PATH: "netRemote.play.signalStrength"
-    PlaySignalStrength,
-
-    /// Returns status of the player
-    ///
-    /// 1=buffering/loading, 2=playing, 3=paused
+impl FsApi {
+    /// Creates a client for the radio at `host` with the default settings
     ///
-    /// Method: GET
-    /// Returns: Value::U8([0,3]),
-    /// PATH: "netRemote.play.status"
-    PlayStatus,
+    /// Use [`FsApi::builder`] to change the timeouts or user agent.
+    pub fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
+        Self::builder(host, pin).build()
+    }
 
-    // play.info
-    /// Returns  the name of the artist of the current song
-    ///
-    /// Method: GET
-    /// Returns: Value::Text(_),
-    /// PATH: "netRemote.play.info.album"
-    PlayInfoAlbum,
+    /// Starts building a client for the radio at `host`
+    pub fn builder<D: Display>(host: D, pin: u32) -> FsApiBuilder {
+        FsApiBuilder::new(host, pin)
+    }
 
-    /// Returns the name of the album of the current song
-    ///
-    /// Method: GET
-    /// Returns: Value::Text(_),
-    /// PATH: "netRemote.play.info.artist"
-    PlayInfoArtist,
+    /// Host (ip or hostname) of the radio this client talks to
+    pub fn host(&self) -> &str {
+        &self.host
+    }
 
-    /// Returns the duration for the track in milliseconds
-    ///
-    /// Method: GET
-    /// Returns: Value::U32(_),
-    /// PATH: "netRemote.play.info.duration"
-    PlayInfoDuration,
+    /// Pin used to authenticate with the radio
+    pub fn pin(&self) -> u32 {
+        self.pin
+    }
 
-    /// Returns the uri of an image representing the current song / station
-    ///
-    /// Method: GET
-    /// Returns: Value::Text(_),
-    /// PATH: "netRemote.play.info.graphicUri"
-    PlayInfoGraphicUri,
+    /// Sends a request to the fsapi server over the shared connection
+    /// and parses the response
+    ///
+    /// The HTTP status of the response is added to the `context`.
+    async fn request(&self, url: String, context: &mut ErrorContext) -> Result<Response, Error> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|err| Error::http(err, context.clone()))?;
+
+        context.http_status = Some(response.status());
+
+        let text = response
+            .error_for_status()
+            .map_err(|err| Error::http(err, context.clone()))?
+            .text()
+            .await
+            .map_err(|err| Error::http(err, context.clone()))?;
+
+        Response::from_str(&text).map_err(|err| Error::InvalidResponse {
+            context: context.clone(),
+            payload: text,
+            source: Box::new(err),
+        })
+    }
 
-    /// Returns the first line of the display
+    /// Gets the vlaue of an node
     ///
-    /// Method: GET
-    /// Returns: Value::Text(_),
-    /// PATH: "netRemote.play.info.name"
-    PlayInfoName,
+    /// The type of the returned value is checked against the [`Node::descriptor`].
+    pub async fn get(&self, node: Node) -> Result<Value, Error> {
+        node.check_readable()?;
 
-    /// Returns  the second line of the display
-    ///
-    /// Method: GET
-    /// Returns: Value::Text(_),
-    /// PATH: "netRemote.play.info.text"
-    PlayInfoText,
-
-    // play.serviceIds,
-    /// Returns DAB Ensemble Identifier (decimal notation)
-    /// Note: commonly used in Hex notation.
-    ///
-    /// Method: GET
-    /// Returns: Value::U16(_),
-    /// PATH: "netRemote.play.serviceIds.dabEnsembleId"
-    PlayServiceIdsDabEnsambleId,
-
-    /// Returns the DAB Service Component Identifier (decimal notation)
-    /// Note: Nearly always 0 for audio services - Secondary Component services will
-    /// have a different value
-    ///
-    /// Method: GET
-    /// Returns: Value::U8(_),
-    /// PATH: "netRemote.play.serviceIds.dabScids"
-    PlayServiceIdsDabScids,
+        let (host, pin) = (&self.host, self.pin);
+        let url = format!("http://{host}/{FSAPI_PATH}/{GET_PATH}/{node}?pin={pin}");
+        let mut context = ErrorContext::new(Operation::Get, Some(&node));
 
-    /// Returns DAB Service Identifier (decimal notation)
-    /// Note: commonly used in Hex notation.
-    ///
-    /// Method: GET
-    /// Returns: Value::U32(_),
-    /// PATH: "netRemote.play.serviceIds.dabServiceId"
-    PlayServiceIdsDabServiceId,
+        let reponse = self.request(url, &mut context).await?;
 
-    /// Returns Extended Country Code (decimal notation) as defined in ETSI TS 101 756
-    /// Note: commonly used in Hex notation.
-    ///
-    /// Method: GET
-    /// Returns: Value::U8(_),
-    /// PATH: "netRemote.play.serviceIds.ecc"
-    PlayServiceIdsEcc,
+        reponse.status.check(&context)?;
 
-    /// Returns RDS Programme Identification code
-    ///
-    /// Method: GET
-    /// Returns: Value::U16(_),
-    /// PATH: "netRemote.play.serviceIds.fmRdsPi"
-    PlayServiceIdsfmRdsPi,
+        match reponse.data {
+            Some(Data::Value(value)) => node.check_value(value, &context),
+            _ => Err(Error::InvalidData(context)),
+        }
+    }
 
-    // sys
-    /// Retrieve/set the radio user interface language
+    /// Sets the vlaue of an node
     ///
-    /// Method: GET, SET
-    /// Returns: `Value::U32(_)`
-    /// PATH: "netRemote.sys.lang"
-    SysLang,
+    /// Nodes that are not writable according to their [`Node::descriptor`]
+    /// are rejected without sending a request.
+    pub async fn set<V: Display>(&self, node: Node, param: V) -> Result<(), Error> {
+        let param = param.to_string();
+        node.check_writable(&param)?;
 
-    /// Sets / Returns the current operation mode
-    ///
-    /// see SysCapsValidModes for valid operation modes
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::U32(_)`
-    /// PATH: "netRemote.sys.mode"
-    SysMode,
+        let (host, pin) = (&self.host, self.pin);
+        let value = encode_query(&param);
+        let url = format!("http://{host}/{FSAPI_PATH}/{SET_PATH}/{node}?pin={pin}&value={value}");
+        let mut context = ErrorContext::new(Operation::Set, Some(&node));
 
-    /// Sets / Returns the current power state
-    ///
-    /// If device returns from standby it will only auto-continue to play in radio-modes.
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::U32([0,1])``
-    /// PATH: "netRemote.sys.power"
-    SysPower,
+        let response = self.request(url, &mut context).await?;
 
-    /// Sets / Returns the Time till Sleep in seconds (0 = No Sleep) [works with Firmware V2.9.10 but not with V2.6.17]
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::U32(_)``
-    /// PATH: "netRemote.sys.sleep"
-    SysSleep,
+        response.status.check(&context)?;
 
-    /// ???
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::U8([0,1])``
-    /// PATH: "netRemote.sys.state"
-    SysState,
+        match response.data {
+            None => Ok(()),
+            _ => Err(Error::InvalidData(context)),
+        }
+    }
 
-    // sys.audio
-    /// Sets / Returns the first value for costum eq-settings (Bass)
+    /// Gets the value of a node converted to its Rust type
     ///
-    /// Method: GET, SET
-    /// Returns: `Value::S16([-7, 7])`
-    /// PATH: "netRemote.sys.audio.eqCustom.param0"
-    SysAudioEqCustomParam0,
+    /// See [`node`] for the available nodes.
+    pub async fn get_typed<N: TypedNode>(&self) -> Result<N::Type, Error> {
+        N::decode(self.get(N::NODE).await?)
+    }
 
-    /// Sets/Returns the first value for costum eq-settings (Treble)
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::S16([-7, 7])`
-    /// PATH: "netRemote.sys.audio.eqCustom.param1"
-    SysAudioEqCustomParam1,
+    /// Sets the value of a node from its Rust type
+    pub async fn set_typed<N: WritableNode>(&self, value: N::Type) -> Result<(), Error> {
+        self.set(N::NODE, N::encode(value)).await
+    }
 
-    /// Sets / Returns whether or not loudness is activated
-    ///
-    /// This function is only available if costum eq is active
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::U8([0, 1])`
-    /// PATH: netRemote.sys.audio.eqLoudness
-    SysAudioEqLoudness,
+    /// Gets the values of multiple nodes in a single request
+    ///
+    /// Every node gets its own result, so one failing node does not fail the others.
+    pub async fn get_multiple<I: IntoIterator<Item = Node>>(
+        &self,
+        nodes: I,
+    ) -> Result<HashMap<Node, Result<Value, Error>>, Error> {
+        let (host, pin) = (&self.host, self.pin);
+        let nodes: Vec<Node> = nodes.into_iter().collect();
+        for node in &nodes {
+            node.check_readable()?;
+        }
+        let nodes: String = nodes.iter().map(|node| format!("&node={node}")).collect();
+        let url = format!("http://{host}/{FSAPI_PATH}/{GET_MULTIPLE_PATH}?pin={pin}{nodes}");
+        let mut context = ErrorContext::new(Operation::GetMultiple, None);
 
-    /// Sets / Returns the number of the selected eq-presets
-    ///
-    /// see: SysCapsEqPresets for valid presets.
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::U8(_)`
-    /// PATH: netRemote.sys.audio.eqPreset
-    SysAudioEqPreset,
+        let response = self.request(url, &mut context).await?;
 
-    /// Sets / Returns whether or not device is muted
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::U8([0, 1])`
-    /// PATH: netRemote.sys.audio.mute
-    SysAudioMute,
+        response.status.check(&context)?;
 
-    /// Sets / Returns the volume of the device
-    ///
-    /// Method: GET, SET
-    /// Returns: `Value::U8([0, 20])`
-    /// PATH: netRemote.sys.audio.volume
-    SysAudioVolume,
+        match response.data {
+            Some(Data::Multiple(responses)) => Ok(responses
+                .into_iter()
+                .map(|response| response.into_result(&context))
+                .map(|(node, result)| {
+                    let result = result
+                        .and_then(|value| {
+                            value.ok_or_else(|| Error::InvalidData(context.with_node(&node)))
+                        })
+                        .and_then(|value| node.check_value(value, &context));
+                    (node, result)
+                })
+                .collect()),
+            _ => Err(Error::InvalidData(context)),
+        }
+    }
 
-    // sys.caps
-    /// Fetch the list of available time sources
-    ///
-    /// Method: LIST_GET_NEXT
-    /// Returns: `Items(_)`
-    /// PATH: netRemote.sys.caps.clockSourList?maxItems=4
-    SysCapsClockSourceList,
+    /// Sets the values of multiple nodes in a single request
+    ///
+    /// Every node gets its own result, so one failing node does not fail the others.
+    pub async fn set_multiple<V: Display, I: IntoIterator<Item = (Node, V)>>(
+        &self,
+        params: I,
+    ) -> Result<HashMap<Node, Result<(), Error>>, Error> {
+        let (host, pin) = (&self.host, self.pin);
+        let params: Vec<(Node, String)> = params
+            .into_iter()
+            .map(|(node, param)| (node, param.to_string()))
+            .collect();
+        for (node, param) in &params {
+            node.check_writable(param)?;
+        }
+        let params: String = params
+            .iter()
+            .map(|(node, param)| format!("&node={node}&value={}", encode_query(param)))
+            .collect();
+        let url = format!("http://{host}/{FSAPI_PATH}/{SET_MULTIPLE_PATH}?pin={pin}{params}");
+        let mut context = ErrorContext::new(Operation::SetMultiple, None);
 
-    /// Lists available dab-frequencies
-    ///
-    /// Method: LIST_GET_NEXT
-    /// Returns: `Items(_)`
-    /// PATH: netRemote.sys.caps.dabFreqList
-    SysCapsDabFreqList,
+        let response = self.request(url, &mut context).await?;
 
-    /// Lists setted modes for the eq
-    ///
-    /// Method: LIST_GET_NEXT
-    /// Returns: `Items(_)`
-    /// PATH: netRemote.sys.caps.eqBands
-    SysCapsEqBands,
+        response.status.check(&context)?;
 
-    /// Lists available eq-presets
-    ///
-    /// Method: LIST_GET_NEXT
-    /// Returns: `Items(_)`
-    /// PATH: netRemote.sys.caps.eqPresets
-    SysCapsEqPresets,
+        match response.data {
+            Some(Data::Multiple(responses)) => Ok(responses
+                .into_iter()
+                .map(|response| response.into_result(&context))
+                .map(|(node, result)| {
+                    let result = result.and_then(|value| match value {
+                        None => Ok(()),
+                        Some(_) => Err(Error::InvalidData(context.with_node(&node))),
+                    });
+                    (node, result)
+                })
+                .collect()),
+            _ => Err(Error::InvalidData(context)),
+        }
+    }
 
-    /// Returns the lowest available fm-frequency
+    /// Gets every item of a list stored in the node
     ///
-    /// Method: GET
-    /// Returns: `Value::U32(_)`
-    /// PATH: netRemote.sys.caps.fmFreqRange.lower
-    SysCapsFmFreqRangeLower,
+    /// All pages are fetched before returning, use [`FsApi::list`]
+    /// to handle the items while they are coming in.
+    pub async fn get_item_list(
+        &self,
+        node: Node,
+        session_id: Option<SessionID>,
+    ) -> Result<Vec<Item>, Error> {
+        self.list(node, session_id).try_collect().await
+    }
 
-    /// Returns the size of the steps for increasing / decreasing the frequency
+    /// Streams every item of a list stored in the node
     ///
-    /// Method: GET
-    /// Returns: `Value::U32(_)`
-    /// PATH: netRemote.sys.caps.fmFreqRange.stepSize
-    SysCapsFmFreqRangeStepSize,
+    /// The list is fetched page by page (see [`FsApiBuilder::list_page_size`]),
+    /// each page starting after the key of the last item of the previous one.
+    /// The stream ends after the last item or after the first error,
+    /// which is [`Error::ListStalled`] if a page does not advance.
+    pub fn list(
+        &self,
+        node: Node,
+        session_id: Option<SessionID>,
+    ) -> impl Stream<Item = Result<Item, Error>> + 'static {
+        let fsapi = self.clone();
+
+        stream::unfold(ListCursor::Start, move |cursor| {
+            let fsapi = fsapi.clone();
+            let node = node.clone();
+
+            async move {
+                let start = match cursor {
+                    ListCursor::Start => -1,
+                    ListCursor::After(key) => i64::from(key),
+                    ListCursor::End => return None,
+                };
+
+                match fsapi.get_item_page(&node, session_id, start).await {
+                    Ok((items, list_end)) => {
+                        let cursor = match items.last() {
+                            Some(item) if !list_end => ListCursor::After(item.key),
+                            _ => ListCursor::End,
+                        };
+
+                        Some((items.into_iter().map(Ok).collect(), cursor))
+                    }
+                    Err(err) => Some((vec![Err(err)], ListCursor::End)),
+                }
+            }
+        })
+        .flat_map(stream::iter)
+    }
 
-    /// Returns the highest available fm-frequency
+    /// Get the next "page" of a list stored in the node,
+    /// starting after the item with key `start` (-1 for the first page)
     ///
-    /// Method: GET
-    /// Returns: `Value::U32(_)`
-    /// PATH: netRemote.sys.caps.fmFreqRange.upper
-    SysCapsFmFreqRangeUpper,
-
-    /// Lists valid operations modes
-    /// Method: LIST_GET_NEXT
-    /// Returns: `Items(_)`
-    /// PATH: netRemote.sys.caps.validModes
-    SysCapsValidModes,
-
-    /// Returns the max volume level
-    ///
-    /// Method: GET
-    /// Returns: `Value::U8(_)`
-    /// PATH: netRemote.sys.caps.volumeSteps
-    SysCapsVolumeSteps,
+    /// Also returns whether or not this was the last page.
+    async fn get_item_page(
+        &self,
+        node: &Node,
+        session_id: Option<SessionID>,
+        start: i64,
+    ) -> Result<(Vec<Item>, bool), Error> {
+        node.check_listable()?;
 
-    // sys.clock
-    /// Fetch/set daylight saving setting
-    ///
-    /// Method: SET/GET
-    /// Returns: `Value::U8([0,1])`
-    /// PATH: netRemote.sys.clock.dst
-    SysClockDst,
+        let (host, pin) = (&self.host, self.pin);
+        let max_items = self.list_page_size;
+        let url = match session_id {
+            None => format!("http://{host}/{FSAPI_PATH}/{LIST_GET_NEXT_PATH}/{node}/{start}?pin={pin}&maxItems={max_items}"),
+            Some(sid) => format!(
+                "http://{host}/{FSAPI_PATH}/{LIST_GET_NEXT_PATH}/{node}/{start}?pin={pin}&SID={sid}&maxItems={max_items}"
+            ),
+        };
 
-    /// Returns the local Date in XML-RPC date format ( 20150914 = 2015-09-14)
-    ///
-    /// Method: GET
-    /// Returns: `Value::Text(String)`
-    /// PATH: netRemote.sys.clock.localDate
-    SysClockLocalDate,
+        let mut context = ErrorContext::new(Operation::ListGetNext, Some(node));
 
-    /// Returns the local time in XML-RPC date format ( 093327 = 09:33:27)
-    ///
-    /// Method: GET
-    /// Returns: `Value::Text(String)`
-    /// PATH: netRemote.sys.clock.localTime
-    SysClockLocalTime,
+        let response = self.request(url, &mut context).await?;
 
-    /// Set the clock to 12h or 24 hour mode
-    ///
-    /// Values:
-    /// - 0: 12h
-    /// - 1: 24h
-    /// Method: SET/GET
-    /// Returns: `Value::U8([0,1])`
-    /// PATH: netRemote.sys.clock.mode
-    SysClockMode,
-
-    /// Fetch/set the time source.
-    ///
-    /// Manual: 0
-    /// DAB: 1
-    /// FM: 2
-    /// ???: 3
-    /// Internet: 4
-    ///
-    /// Valid values can be fetched with `SysCapsClockSourceList`.
-    ///
-    /// Method: SET/GET
-    /// Returns: `Value::U8(_)`
-    /// PATH: netRemote.sys.clock.source
-    SysClockSource,
+        match response.status {
+            ResponseStatus::ListEnd => return Ok((Vec::new(), true)),
+            status => status.check(&context)?,
+        }
 
-    /// Get/set the offset of the local time compared to UTC.
-    ///
-    /// Valid values can be fetched with `SysCapsUtcSettingsList`.
-    ///
-    /// Method: SET/GET
-    /// Returns: `Value::U8(_)`
-    /// PATH: netRemote.sys.clock.utcOffset
-    SysClockUtcOffset,
+        match response.data {
+            // A radio ignoring the start would send the same page forever
+            Some(Data::Items { items, .. })
+                if items
+                    .last()
+                    .is_some_and(|item| i64::from(item.key) <= start) =>
+            {
+                Err(Error::ListStalled(context))
+            }
+            Some(Data::Items { items, list_end }) => Ok((items, list_end)),
+            None => Ok((Vec::new(), true)),
+            _ => Err(Error::InvalidData(context)),
+        }
+    }
 
-    // sys.cfg
-    /// TODO: description
-    ///
-    /// Is part of the new API Version.
+    /// This is a special Command: The device does not close the HTTP-Request.
     ///
-    /// Method: SET/GET
-    /// Returns: `Value::U8(_)`
-    /// PATH: netRemote.sys.cfg.irAutoPlayFlag
-    SysCfgIrAutoPlayFlag,
-
-    // sys.info
-    /// Sets/ Returns the Network-Name of the Device
+    /// With this command you can use the connection like a normal socket,
+    /// where the device sends you updates about changed nodes.
     ///
-    /// Method: SET/GET
-    /// Returns: `Value::Text(String)`
-    /// PATH: netRemote.sys.info.friendlyName
-    SysInfoFriendlyName,
-
-    /// Returns unique? ID Radio-ID
+    /// This Command is only available if a Session-ID is used to authenticate.
     ///
-    /// Method: GET
-    /// Returns: `Value::Text(String)`
-    /// PATH: netRemote.sys.info.radioId
-    SysInfoRadioId,
+    /// It gives you a FS_TIMEOUT error if nothing has changed.
+    pub async fn get_notifications(
+        &self,
+        session_id: SessionID,
+    ) -> Result<Option<Vec<Notification>>, Error> {
+        let (host, pin) = (&self.host, self.pin);
+        let url =
+            format!("http://{host}/{FSAPI_PATH}/{GET_NOTIFIES_PATH}?pin={pin}&sid={session_id}");
 
-    /// Change the PIN used to access the radio API.
-    ///
-    /// Method: SET
-    /// Value: pin
-    /// PATH: netRemote.sys.info.radioPin
-    SysInfoRadioPin,
+        let mut context = ErrorContext::new(Operation::GetNotifies, None);
 
-    /// Returns Image-Version String
-    ///
-    /// Method: GET
-    /// Returns: `Value::Text(String)`
-    /// PATH: netRemote.sys.info.version
-    SysInfoVersion,
+        let response = self.request(url, &mut context).await?;
 
-    /// Get or Set the Name of the Device which is remote-controling the radio
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::Text(String)`
-    /// PATH: netRemote.sys.info.controllerName
-    SysInfoControlName,
+        match response.status {
+            ResponseStatus::Timeout => return Ok(None),
+            status => status.check(&context)?,
+        }
 
-    // sys.isu
-    /// TODO: Descscription
-    ///
-    /// Value:
-    ///     2 = search for updates
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::U8(_)`
-    /// PATH: netRemote.sys.isu.control
-    SysIsuControl,
+        match response.data {
+            Some(Data::Notify(notifications)) => Ok(Some(notifications)),
+            _ => Err(Error::InvalidData(context)),
+        }
+    }
 
-    /// Shows the update process, default 0
-    ///
-    /// while searching: 1
-    /// after searching: 0
-    /// ???: 3
+    /// Streams the notifications of the session
     ///
-    /// Method: GET
-    /// Returns: `Value::U8([0,1])`
-    /// PATH: netRemote.sys.isu.state
-    SysIsuState,
-
-    // sys.net
-    // sys.net.ipConfig
-    /// Returns/sets? the IP address for the connected network
+    /// The `FS_TIMEOUT`s of the long poll are handled and the radio is polled
+    /// again after network errors, waiting longer after each failed attempt.
+    /// The stream ends after any other error, drop it to stop listening.
     ///
-    /// Method: GET/SET
-    /// Returns: `Value::U32(_)`
-    /// PATH: netRemote.sys.net.ipConfig.address
-    SysNetIpConfigAddress,
+    /// Use [`SessionManager::notifications`] to keep listening
+    /// when the session is purged by another client.
+    pub fn notifications(
+        &self,
+        session_id: SessionID,
+    ) -> impl Stream<Item = Result<Notification, Error>> + Unpin + Send + 'static {
+        let fsapi = self.clone();
 
-    /// Returns if DHCP is enabled for the connected network
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::U8([0,1])`
-    /// PATH: netRemote.sys.net.ipConfig.dhcp
-    SysNetIpConfigDhcp,
+        notifications::poll_notifications(
+            move || {
+                let fsapi = fsapi.clone();
+                async move { fsapi.get_notifications(session_id).await }
+            },
+            Backoff::forever(),
+        )
+    }
 
-    /// Returns the primary dns for the connected network
+    /// Login and create a new session
     ///
-    /// Method: GET/SET
-    /// Returns: `Value::U32(_)`
-    /// PATH: netRemote.sys.net.ipConfig.dnsPrimary
-    SysNetIpConfigDnsPrimary,
+    /// There can only be 1 session at a time.
+    /// If a new is created while another existed the old one will be purged
+    pub async fn create_session(&self) -> Result<SessionID, Error> {
+        let (host, pin) = (&self.host, self.pin);
+        let url = format!("http://{host}/{FSAPI_PATH}/{CREATE_SESSION_PATH}?pin={pin}");
 
-    /// Returns the secundary dns for the connected network
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::U32(_)`
-    /// PATH: netRemote.sys.net.ipConfig.dnsSecundary
-    SysNetIpConfigDnsSecundary,
+        let mut context = ErrorContext::new(Operation::CreateSession, None);
 
-    /// Returns the default gateway for the connected network
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::U32(_)`
-    /// PATH: netRemote.sys.net.ipConfig.gateway
-    SysNetIpConfigGateway,
+        let response = self.request(url, &mut context).await?;
 
-    /// Returns the subnet masky for the connected network
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::U32(_)`
-    /// PATH: netRemote.sys.net.ipConfig.subnetMask
-    SysNetIpConfigSubnetMask,
+        response.status.check(&context)?;
 
-    /// If set to 1 network connection is not disconnected in standby
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::U8([0,1])`
-    /// PATH: netRemote.sys.net.ipConfig.keepConnected
-    SysNetIpConfigKeepConnected,
+        match response.data {
+            Some(Data::SessionID(session_id)) => Ok(session_id),
+            _ => Err(Error::InvalidData(context)),
+        }
+    }
 
-    /// Returns the NIC Status of the Ethernet Device
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::U8(_)`
-    /// PATH: netRemote.sys.net.ipConfig.wired.interfaceEnable
-    SysNetWiredInterfaceEnable,
+    /// Logout and destroy the session
+    pub async fn delete_session(&self, session_id: SessionID) -> Result<(), Error> {
+        let (host, pin) = (&self.host, self.pin);
+        let url =
+            format!("http://{host}/{FSAPI_PATH}/{DELETE_SESSION_PATH}?pin={pin}&sid={session_id}");
 
-    /// Returns the MAC Address of the Ethernet Device
-    ///
-    /// Method: GET
-    /// Returns: `Value::Text(_)`
-    /// PATH: netRemote.sys.net.ipConfig.wired.macAddress
-    SysNetWiredInterfaceMacAddress,
+        let mut context = ErrorContext::new(Operation::DeleteSession, None);
 
-    /// Returns the SSID of the connected WIFI network
-    ///
-    /// Method: GET
-    ///
-    /// Returns: `Value::Text(_)`
-    /// PATH: netRemote.sys.net.ipConfig.wired.macAddress
-    SysNetWlanConnectedSSID,
+        let response = self.request(url, &mut context).await?;
 
-    /// Returns the NIC Status of the WIFI Device
-    ///
-    /// Method: GET/SET
-    /// Returns: `Value::U8(_)`
-    /// PATH: netRemote.sys.net.ipConfig.wlan.interfaceEnable
-    SysNetWlanInterfaceEnable,
+        response.status.check(&context)?;
 
-    /// Returns the MAC Address of the WIFI Device
-    ///
-    /// Method: GET
-    /// Returns: `Value::Text(_)`
-    /// PATH: netRemote.sys.net.ipConfig.wlan.macAddress
-    SysNetWlanInterfaceMacAddress,
+        match response.data {
+            None => Ok(()),
+            _ => Err(Error::InvalidData(context)),
+        }
+    }
 
-    /// Returns the Signal Strenght of the connected WIFI network
+    /// Downloads a file, like the image behind [`Node::PlayInfoGraphicUri`]
     ///
-    /// Method: GET
-    /// Returns: `Value::u8(_)`
-    /// PATH: netRemote.sys.net.ipConfig.wlan.rssi
-    SysNetWlanRssi,
+    /// Uses the connection pool of this client.
+    /// A path without a host is resolved against the radio.
+    /// Fails with [`Error::TooLarge`] as soon as the file is known to be
+    /// bigger than `max_size` bytes, without reading the rest.
+    pub async fn download(&self, url: &str, max_size: u64) -> Result<Vec<u8>, Error> {
+        let url = match url.starts_with('/') {
+            true => format!("http://{}{url}", self.host),
+            false => url.to_string(),
+        };
 
-    /// Returns the ??? of the connected WIFI network
-    ///
-    /// Method: GET
-    /// Returns: `Value::u8(_)`
-    /// PATH: netRemote.sys.net.ipConfig.wlan.setAuthType
-    SysNetWlanSetAuthType,
+        let mut context = ErrorContext::new(Operation::Download, None);
 
-    /// Returns the encryption type of the connected WIFI network
-    ///
-    /// Method: GET
-    /// Returns: `Value::u8(_)`
-    /// PATH: netRemote.sys.net.ipConfig.wlan.setEncType
-    SysNetWlanSetEncType,
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|err| Error::http(err, context.clone()))?;
 
-    /// Fetch the public RSA key that is used to encrypt the Wifi password before
-    /// sending it to the radio.
-    ///
-    /// Method: GET
-    /// Returns: `Value::u32(_)`
-    /// PATH: netRemote.sys.rsa.publicKey
-    SysRsaPublicKey,
-
-    /// Check the status of the RSA key generation.
-    /// - 0: Generating
-    /// - 1: Ready
-    ///
-    /// Method: GET
-    /// Returns: `Value::u32(_)`
-    /// PATH: netRemote.sys.rsa.status
-    SysRsaStatus,
-}
+        context.http_status = Some(response.status());
 
-impl std::fmt::Display for SessionID {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
+        let mut response = response
+            .error_for_status()
+            .map_err(|err| Error::http(err, context.clone()))?;
 
-impl std::fmt::Display for Node {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        use Node::*;
-        let node = match self {
-            // nav
-            NavList => NODE_NAV_LIST,
-            NavNumItems => NODE_NAV_NUMITEMS,
-            NavPresets => NODE_NAV_PRESETS,
-            NavSearchTerm => NODE_NAV_SEARCHTERM,
-            NavState => NODE_NAV_STATE,
-            NavStatus => NODE_NAV_STATUS,
-            NavDepth => NODE_NAV_DEPTH,
-
-            // nav.action
-            NavActionDabScan => NODE_NAV_ACTION_DABSCAN,
-            NavActionNavigate => NODE_NAV_ACTION_NAVIGATE,
-            NavActionSelectItem => NODE_NAV_ACTION_SELECTITEM,
-            NavActionSelectPreset => NODE_NAV_ACTION_SELECTPRESET,
-
-            // play
-            PlayAddPreset => NODE_PLAY_ADDPRESET,
-            PlayCaps => NODE_PLAY_CAPS,
-            PlayControl => NODE_PLAY_CONTROL,
-            PlayErrorStr => NODE_PLAY_ERRORSTR,
-            PlayFrequency => NODE_PLAY_FREQUENCY,
-            PlayPosition => NODE_PLAY_POSITION,
-            PlayRate => NODE_PLAY_RATE,
-            PlayRepeat => NODE_PLAY_REPEAT,
-            PlayScrobble => NODE_PLAY_SCROBBLE,
-            PlayShuffle => NODE_PLAY_SHUFFLE,
-            PlayShuffleStatus => NODE_PLAY_SHUFFLESTATUS,
-            PlaySignalStrength => NODE_PLAY_SIGNALSTRENGTH,
-            PlayStatus => NODE_PLAY_STATUS,
-
-            // play.info
-            PlayInfoAlbum => NODE_PLAY_INFO_ALBUM,
-            PlayInfoArtist => NODE_PLAY_INFO_ARTIST,
-            PlayInfoDuration => NODE_PLAY_INFO_DURATION,
-            PlayInfoGraphicUri => NODE_PLAY_INFO_GRAPHICURI,
-            PlayInfoName => NODE_PLAY_INFO_NAME,
-            PlayInfoText => NODE_PLAY_INFO_TEXT,
-
-            // play.serviceIds => NODE_PLAY_
-            PlayServiceIdsDabEnsambleId => NODE_PLAY_SERVICEIDS_DABENSEMBLEID,
-            PlayServiceIdsDabScids => NODE_PLAY_SERVICEIDS_DABSCIDS,
-            PlayServiceIdsDabServiceId => NODE_PLAY_SERVICEIDS_DABSERVICEID,
-            PlayServiceIdsEcc => NODE_PLAY_SERVICEIDS_ECC,
-            PlayServiceIdsfmRdsPi => NODE_PLAY_SERVICEIDS_FMRDSPI,
-
-            // sys
-            SysLang => NODE_SYS_LANG,
-            SysMode => NODE_SYS_MODE,
-            SysPower => NODE_SYS_POWER,
-            SysSleep => NODE_SYS_SLEEP,
-            SysState => NODE_SYS_STATE,
-
-            // sys.audio
-            SysAudioEqCustomParam0 => NODE_SYS_AUDIO_EQCUSTOM_PARAM0,
-            SysAudioEqCustomParam1 => NODE_SYS_AUDIO_EQCUSTOM_PARAM1,
-
-            SysAudioEqLoudness => NODE_SYS_AUDIO_EQLOUDNESS,
-            SysAudioEqPreset => NODE_SYS_AUDIO_EQPRESET,
-            SysAudioVolume => NODE_SYS_AUDIO_VOLUME,
-            SysAudioMute => NODE_SYS_AUDIO_MUTE,
-
-            // sys.caps
-            SysCapsClockSourceList => NODE_SYS_CAPS_CLOCKSOURCELIST,
-            SysCapsDabFreqList => NODE_SYS_CAPS_DABFREQLIST,
-            SysCapsEqBands => NODE_SYS_CAPS_EQBANDS,
-            SysCapsEqPresets => NODE_SYS_CAPS_EQPRESETS,
-
-            SysCapsFmFreqRangeLower => NODE_SYS_CAPS_FMFREQRANGE_LOWER,
-            SysCapsFmFreqRangeStepSize => NODE_SYS_CAPS_FMFREQRANGE_STEPSIZE,
-            SysCapsFmFreqRangeUpper => NODE_SYS_CAPS_FMFREQRANGE_UPPER,
-
-            SysCapsValidModes => NODE_SYS_CAPS_VALIDMODES,
-            SysCapsVolumeSteps => NODE_SYS_CAPS_VOLUMESTEPS,
-
-            // sys.clock
-            SysClockDst => NODE_SYS_CLOCK_DST,
-            SysClockLocalDate => NODE_SYS_CLOCK_LOCALDATE,
-            SysClockLocalTime => NODE_SYS_CLOCK_LOCALTIME,
-            SysClockMode => NODE_SYS_CLOCK_MODE,
-            SysClockSource => NODE_SYS_CLOCK_SOURCE,
-            SysClockUtcOffset => NODE_SYS_CLOCK_UTCOFFSET,
-
-            SysCfgIrAutoPlayFlag => NODE_SYS_CFG_IRAUTOPLAYFLAG,
-
-            // sys.info
-            SysInfoFriendlyName => NODE_INFO_FRIENDLYNAME,
-            SysInfoRadioId => NODE_INFO_RADIOID,
-            SysInfoRadioPin => NODE_INFO_RADIOPIN,
-            SysInfoVersion => NODE_INFO_VERSION,
-            SysInfoControlName => NODE_INFO_CONTROLLERNAME,
-
-            // sys.isu
-            SysIsuControl => NODE_ISU_CONTROL,
-            SysIsuState => NODE_ISU_STATE,
-
-            // sys.net
-            // sys.net.ipConfig
-            SysNetIpConfigAddress => NODE_NET_IPCONFIG_ADDRESS,
-            SysNetIpConfigDhcp => NODE_NET_IPCONFIG_DHCP,
-            SysNetIpConfigDnsPrimary => NODE_NET_IPCONFIG_DNSPRIMARY,
-            SysNetIpConfigDnsSecundary => NODE_NET_IPCONFIG_DNSECUNDARY,
-            SysNetIpConfigGateway => NODE_NET_IPCONFIG_GATEWAY,
-            SysNetIpConfigSubnetMask => NODE_NET_IPCONFIG_SUBNETMASK,
-            SysNetIpConfigKeepConnected => NODE_NET_IPCONFIG_KEEPCONNECTED,
-            SysNetWiredInterfaceEnable => NODE_NET_WIRED_INTERFACEENABLE,
-            SysNetWiredInterfaceMacAddress => NODE_NET_WIRED_MACADDRESS,
-            SysNetWlanConnectedSSID => NODE_NET_WLAN_CONNECTEDSSID,
-            SysNetWlanInterfaceEnable => NODE_NET_WLAN_INTERFACEENABLE,
-            SysNetWlanInterfaceMacAddress => NODE_NET_WLAN_MACADDRESS,
-            SysNetWlanRssi => NODE_NET_WLAN_RSSI,
-            SysNetWlanSetAuthType => NODE_NET_WLAN_SETAUTHTYPE,
-            SysNetWlanSetEncType => NODE_NET_WLAN_SETENCTYPE,
-
-            // sys.rsa
-            SysRsaPublicKey => NODE_SYS_RSA_PUBLICKEY,
-            SysRsaStatus => NODE_SYS_RSA_STATUS,
-        };
-        write!(f, "{node}")
-    }
-}
+        let too_large = |context: ErrorContext| Error::TooLarge { context, max_size };
+        if response
+            .content_length()
+            .is_some_and(|length| length > max_size)
+        {
+            return Err(too_large(context));
+        }
 
-impl TryFrom<String> for Node {
-    type Error = Error;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        use Node::*;
-        let node = match value.as_str() {
-            // nav
-            NODE_NAV_LIST => NavList,
-            NODE_NAV_NUMITEMS => NavNumItems,
-            NODE_NAV_PRESETS => NavPresets,
-            NODE_NAV_SEARCHTERM => NavSearchTerm,
-            NODE_NAV_STATE => NavState,
-            NODE_NAV_STATUS => NavStatus,
-            NODE_NAV_DEPTH => NavDepth,
-
-            // nav.action
-            NODE_NAV_ACTION_DABSCAN => NavActionDabScan,
-            NODE_NAV_ACTION_NAVIGATE => NavActionNavigate,
-            NODE_NAV_ACTION_SELECTITEM => NavActionSelectItem,
-            NODE_NAV_ACTION_SELECTPRESET => NavActionSelectPreset,
-
-            // play
-            NODE_PLAY_ADDPRESET => PlayAddPreset,
-            NODE_PLAY_CAPS => PlayCaps,
-            NODE_PLAY_CONTROL => PlayControl,
-            NODE_PLAY_ERRORSTR => PlayErrorStr,
-            NODE_PLAY_FREQUENCY => PlayFrequency,
-            NODE_PLAY_POSITION => PlayPosition,
-            NODE_PLAY_RATE => PlayRate,
-            NODE_PLAY_REPEAT => PlayRepeat,
-            NODE_PLAY_SCROBBLE => PlayScrobble,
-            NODE_PLAY_SHUFFLE => PlayShuffle,
-            NODE_PLAY_SHUFFLESTATUS => PlayShuffleStatus,
-            NODE_PLAY_SIGNALSTRENGTH => PlaySignalStrength,
-            NODE_PLAY_STATUS => PlayStatus,
-
-            // play.info
-            NODE_PLAY_INFO_ALBUM => PlayInfoAlbum,
-            NODE_PLAY_INFO_ARTIST => PlayInfoArtist,
-            NODE_PLAY_INFO_DURATION => PlayInfoDuration,
-            NODE_PLAY_INFO_GRAPHICURI => PlayInfoGraphicUri,
-            NODE_PLAY_INFO_NAME => PlayInfoName,
-            NODE_PLAY_INFO_TEXT => PlayInfoText,
-
-            // play.serviceIds => NODE_PLAY_
-            NODE_PLAY_SERVICEIDS_DABENSEMBLEID => PlayServiceIdsDabEnsambleId,
-            NODE_PLAY_SERVICEIDS_DABSCIDS => PlayServiceIdsDabScids,
-            NODE_PLAY_SERVICEIDS_DABSERVICEID => PlayServiceIdsDabServiceId,
-            NODE_PLAY_SERVICEIDS_ECC => PlayServiceIdsEcc,
-            NODE_PLAY_SERVICEIDS_FMRDSPI => PlayServiceIdsfmRdsPi,
-
-            // sys
-            NODE_SYS_LANG => SysLang,
-            NODE_SYS_MODE => SysMode,
-            NODE_SYS_POWER => SysPower,
-            NODE_SYS_SLEEP => SysSleep,
-            NODE_SYS_STATE => SysState,
-
-            // sys.audio
-            NODE_SYS_AUDIO_EQCUSTOM_PARAM0 => SysAudioEqCustomParam0,
-            NODE_SYS_AUDIO_EQCUSTOM_PARAM1 => SysAudioEqCustomParam1,
-
-            NODE_SYS_AUDIO_EQLOUDNESS => SysAudioEqLoudness,
-            NODE_SYS_AUDIO_EQPRESET => SysAudioEqPreset,
-            NODE_SYS_AUDIO_VOLUME => SysAudioVolume,
-            NODE_SYS_AUDIO_MUTE => SysAudioMute,
-
-            // sys.caps
-            NODE_SYS_CAPS_CLOCKSOURCELIST => SysCapsClockSourceList,
-            NODE_SYS_CAPS_DABFREQLIST => SysCapsDabFreqList,
-            NODE_SYS_CAPS_EQBANDS => SysCapsEqBands,
-            NODE_SYS_CAPS_EQPRESETS => SysCapsEqPresets,
-
-            NODE_SYS_CAPS_FMFREQRANGE_LOWER => SysCapsFmFreqRangeLower,
-            NODE_SYS_CAPS_FMFREQRANGE_STEPSIZE => SysCapsFmFreqRangeStepSize,
-            NODE_SYS_CAPS_FMFREQRANGE_UPPER => SysCapsFmFreqRangeUpper,
-
-            NODE_SYS_CAPS_VALIDMODES => SysCapsValidModes,
-            NODE_SYS_CAPS_VOLUMESTEPS => SysCapsVolumeSteps,
-
-            // sys.clock
-            NODE_SYS_CLOCK_DST => SysClockDst,
-            NODE_SYS_CLOCK_LOCALDATE => SysClockLocalDate,
-            NODE_SYS_CLOCK_LOCALTIME => SysClockLocalTime,
-            NODE_SYS_CLOCK_MODE => SysClockMode,
-            NODE_SYS_CLOCK_SOURCE => SysClockSource,
-            NODE_SYS_CLOCK_UTCOFFSET => SysClockUtcOffset,
-
-            // sys.cfg
-            NODE_SYS_CFG_IRAUTOPLAYFLAG => SysCfgIrAutoPlayFlag,
-
-            // sys.info
-            NODE_INFO_FRIENDLYNAME => SysInfoFriendlyName,
-            NODE_INFO_RADIOID => SysInfoRadioId,
-            NODE_INFO_RADIOPIN => SysInfoRadioPin,
-            NODE_INFO_VERSION => SysInfoVersion,
-            NODE_INFO_CONTROLLERNAME => SysInfoControlName,
-
-            // sys.isu
-            NODE_ISU_CONTROL => SysIsuControl,
-            NODE_ISU_STATE => SysIsuState,
-
-            // sys.net
-            // sys.net.ipConfig
-            NODE_NET_IPCONFIG_ADDRESS => SysNetIpConfigAddress,
-            NODE_NET_IPCONFIG_DHCP => SysNetIpConfigDhcp,
-            NODE_NET_IPCONFIG_DNSPRIMARY => SysNetIpConfigDnsPrimary,
-            NODE_NET_IPCONFIG_DNSECUNDARY => SysNetIpConfigDnsSecundary,
-            NODE_NET_IPCONFIG_GATEWAY => SysNetIpConfigGateway,
-            NODE_NET_IPCONFIG_SUBNETMASK => SysNetIpConfigSubnetMask,
-            NODE_NET_IPCONFIG_KEEPCONNECTED => SysNetIpConfigKeepConnected,
-            NODE_NET_WIRED_INTERFACEENABLE => SysNetWiredInterfaceEnable,
-            NODE_NET_WIRED_MACADDRESS => SysNetWiredInterfaceMacAddress,
-            NODE_NET_WLAN_CONNECTEDSSID => SysNetWlanConnectedSSID,
-            NODE_NET_WLAN_INTERFACEENABLE => SysNetWlanInterfaceEnable,
-            NODE_NET_WLAN_MACADDRESS => SysNetWlanInterfaceMacAddress,
-            NODE_NET_WLAN_RSSI => SysNetWlanRssi,
-            NODE_NET_WLAN_SETAUTHTYPE => SysNetWlanSetAuthType,
-            NODE_NET_WLAN_SETENCTYPE => SysNetWlanSetEncType,
-
-            // sys.rsa
-            NODE_SYS_RSA_PUBLICKEY => SysRsaPublicKey,
-            NODE_SYS_RSA_STATUS => SysRsaStatus,
-
-            node => dbg!(Err(InternalError::new(format!("Unknown node: {node}"))))?,
-        };
+        // The length is not always sent, or could be wrong
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|err| Error::http(err, context.clone()))?
+        {
+            if (bytes.len() + chunk.len()) as u64 > max_size {
+                return Err(too_large(context));
+            }
+            bytes.extend_from_slice(&chunk);
+        }
 
-        Ok(node)
+        Ok(bytes)
     }
 }
 
+/// One-shot wrappers around the [`FsApi`] client
+///
+/// Every call builds a throwaway client, so nothing is shared between calls.
+/// These only exist to ease the migration to a long lived [`FsApi`].
 impl FsApi {
-    /// Gets the vlaue of an node
-    pub async fn get<D: Display>(node: Node, host: D, pin: u32) -> Result<Value, Error> {
-        let url = format!("http://{host}/{FSAPI_PATH}/{GET_PATH}/{node}?pin={pin}");
-
-        let reponse = Response::from_str(&reqwest::get(url).await?.text().await?)?;
-
-        if ResponseStatus::Ok != reponse.status {
-            return Err(Error::InvalidStatus);
-        }
-
-        match reponse.data {
-            Some(Data::Value(value)) => Ok(value),
-            _ => Err(Error::InvalidData),
-        }
+    #[deprecated(note = "build an `FsApi` client once and use `FsApi::get`")]
+    pub async fn get_once<D: Display>(node: Node, host: D, pin: u32) -> Result<Value, Error> {
+        Self::new(host, pin)?.get(node).await
     }
 
-    /// Sets the vlaue of an node
-    pub async fn set<D: Display, V: Display>(
+    #[deprecated(note = "build an `FsApi` client once and use `FsApi::set`")]
+    pub async fn set_once<D: Display, V: Display>(
         node: Node,
         param: V,
         host: D,
         pin: u32,
     ) -> Result<(), Error> {
-        let url = format!("http://{host}/{FSAPI_PATH}/{SET_PATH}/{node}?pin={pin}&value={param}");
-
-        let response = Response::from_str(&reqwest::get(url).await?.text().await?)?;
-
-        if ResponseStatus::Ok != response.status {
-            return Err(Error::InvalidStatus);
-        }
-
-        match response.data {
-            None => Ok(()),
-            _ => Err(Error::InvalidData),
-        }
+        Self::new(host, pin)?.set(node, param).await
     }
 
-    // Get the next "page" of a list stored in the node
-    //
-    // NOTE: currently only 1 page supported, this page has a max of 999 items
-    // should be enough
-    pub async fn get_item_list<D: Display>(
+    #[deprecated(note = "build an `FsApi` client once and use `FsApi::get_item_list`")]
+    pub async fn get_item_list_once<D: Display>(
         node: Node,
         session_id: Option<SessionID>,
         host: D,
         pin: u32,
     ) -> Result<Vec<Item>, Error> {
-        let max_items = 65536;
-        let url = match session_id {
-            None => format!("http://{host}/{FSAPI_PATH}/{LIST_GET_NEXT_PATH}/{node}/-1?pin={pin}&maxItems={max_items}"),
-            Some(sid) => format!(
-                "http://{host}/{FSAPI_PATH}/{LIST_GET_NEXT_PATH}/{node}/-1?pin={pin}&SID={sid}&maxItems={max_items}"
-            ),
-        };
-
-        let response = Response::from_str(&reqwest::get(url).await?.text().await?)?;
-
-        if ResponseStatus::Ok != response.status {
-            return Err(Error::InvalidStatus);
-        }
-        match response.status {
-            ResponseStatus::Ok => (),
-            ResponseStatus::Fail => return Err(Error::Fail),
-            _ => return Err(Error::InvalidStatus),
-        }
-
-        match response.data {
-            Some(Data::Items(items)) => Ok(items),
-            _ => Err(Error::InvalidData),
-        }
+        Self::new(host, pin)?.get_item_list(node, session_id).await
     }
 
-    /// This is a special Command: The device does not close the HTTP-Request.
-    ///
-    /// With this command you can use the connection like a normal socket,
-    /// where the device sends you updates about changed nodes.
-    ///
-    /// This Command is only available if a Session-ID is used to authenticate.
-    ///
-    /// It gives you a FS_TIMEOUT error if nothing has changed.
-    pub async fn get_notifications<D: Display>(
+    #[deprecated(note = "build an `FsApi` client once and use `FsApi::get_notifications`")]
+    pub async fn get_notifications_once<D: Display>(
         session_id: SessionID,
         host: D,
         pin: u32,
     ) -> Result<Option<Vec<Notification>>, Error> {
-        let url =
-            format!("http://{host}/{FSAPI_PATH}/{GET_NOTIFIES_PATH}?pin={pin}&sid={session_id}");
-
-        let response = Response::from_str(&reqwest::get(url).await?.text().await?)?;
-
-        match response.status {
-            ResponseStatus::Ok => (),
-            ResponseStatus::Timeout => return Ok(None),
-            _ => return Err(Error::InvalidStatus),
-        }
-
-        match response.data {
-            Some(Data::Notify(notifications)) => Ok(Some(notifications)),
-            _ => Err(Error::InvalidData),
-        }
+        Self::new(host, pin)?.get_notifications(session_id).await
     }
 
-    /// Login and create a new session
-    ///
-    /// There can only be 1 session at a time.
-    /// If a new is created while another existed the old one will be purged
-    pub async fn create_session<D: Display>(host: D, pin: u32) -> Result<SessionID, Error> {
-        let url = format!("http://{host}/{FSAPI_PATH}/{CREATE_SESSION_PATH}?pin={pin}");
-
-        let response = Response::from_str(&reqwest::get(url).await?.text().await?)?;
-
-        if ResponseStatus::Ok != response.status {
-            return Err(Error::InvalidStatus);
-        }
-
-        match response.data {
-            Some(Data::SessionID(session_id)) => Ok(session_id),
-            _ => Err(Error::InvalidData),
-        }
+    #[deprecated(note = "build an `FsApi` client once and use `FsApi::create_session`")]
+    pub async fn create_session_once<D: Display>(host: D, pin: u32) -> Result<SessionID, Error> {
+        Self::new(host, pin)?.create_session().await
     }
 
-    /// Logout and destroy the session
-    pub async fn delete_session<D: Display>(
+    #[deprecated(note = "build an `FsApi` client once and use `FsApi::delete_session`")]
+    pub async fn delete_session_once<D: Display>(
         session_id: SessionID,
         host: D,
         pin: u32,
     ) -> Result<(), Error> {
-        let url =
-            format!("http://{host}/{FSAPI_PATH}/{DELETE_SESSION_PATH}?pin={pin}&sid={session_id}");
-
-        let response = Response::from_str(&reqwest::get(url).await?.text().await?)?;
-
-        if ResponseStatus::Ok != response.status {
-            return Err(Error::InvalidStatus);
-        }
-
-        match response.data {
-            None => Ok(()),
-            _ => Err(Error::InvalidData),
-        }
-    }
-}
-
-// TODO: rewrite this impl
-impl Value {
-    fn from_reader(reader: &mut quick_xml::Reader<&[u8]>) -> Result<Value, InternalError> {
-        if let Event::Start(ref e) = reader.read_event()? {
-            match e.name() {
-                QName(b"c8_array") => Ok(Value::Text(reader.read_text(e.name())?.to_string())),
-                QName(b"u8") => Ok(Value::U8(reader.read_text(e.name())?.parse()?)),
-                QName(b"s16") => Ok(Value::S16(reader.read_text(e.name())?.parse()?)),
-                QName(b"u32") => Ok(Value::U32(reader.read_text(e.name())?.parse()?)),
-                QName(b"array") => Ok(Value::Array(reader.read_text(e.name())?.to_string())),
-                _ => Err(InternalError::Value(String::from("Unknown option"))),
-            }
-        } else {
-            Err(InternalError::Value(String::from("Incorrect value format")))
-        }
+        Self::new(host, pin)?.delete_session(session_id).await
     }
 }
 
@@ -1381,13 +685,29 @@ impl SessionID {
     }
 }
 
+/// Where the next page of a list starts
+#[derive(Clone, Copy, Debug)]
+enum ListCursor {
+    /// Nothing fetched yet
+    Start,
+
+    /// After the item with this key
+    After(u32),
+
+    /// The whole list has been fetched
+    End,
+}
+
 impl Item {
+    /// Reads every item of the page, also returns whether or not
+    /// the page ended with `<listend/>`
     fn items_from_reader(
         first_key: u32,
         reader: &mut quick_xml::Reader<&[u8]>,
-    ) -> Result<Vec<Self>, InternalError> {
+    ) -> Result<(Vec<Self>, bool), InternalError> {
         let mut items = Vec::new();
         let mut key = first_key;
+        let list_end;
 
         'item: loop {
             let mut fields: Vec<Field> = Vec::new();
@@ -1439,13 +759,25 @@ impl Item {
                     };
                 }
                 Event::Empty(ref e) => match e.name() {
-                    QName(b"listend") => break 'item, // </listend>
+                    // </listend>
+                    QName(b"listend") => {
+                        list_end = true;
+                        break 'item;
+                    }
+                    _ => return Err(InternalError::Item(String::from("Unexpected end"))),
+                },
+                Event::End(ref e) => match e.name() {
+                    // </fsapiResponse> without </listend>, there are more pages
+                    QName(b"fsapiResponse") => {
+                        list_end = false;
+                        break 'item;
+                    }
                     _ => return Err(InternalError::Item(String::from("Unexpected end"))),
                 },
                 _ => return Err(InternalError::Item(String::from("Unexpected event"))),
             };
         }
-        Ok(items)
+        Ok((items, list_end))
     }
 }
 
@@ -1478,7 +810,7 @@ impl Notification {
                     // <notify node="...">
                     match e.attributes().next() {
                         Some(attribute) => {
-                            Node::try_from(String::from_utf8(attribute?.value.to_mut().to_vec())?)?
+                            Node::from(String::from_utf8(attribute?.value.to_mut().to_vec())?)
                         }
                         None => {
                             return Err(InternalError::Notify(String::from(
@@ -1499,11 +831,83 @@ impl Notification {
     }
 }
 
-impl Data {
-    fn from_reader(
+impl NodeResponse {
+    /// Reads every `<fsapiResponse>` until the end of the multiple response
+    fn responses_from_reader(
         reader: &mut quick_xml::Reader<&[u8]>,
-        buf: &mut Vec<u8>,
-    ) -> Result<Option<Self>, InternalError> {
+    ) -> Result<Vec<Self>, InternalError> {
+        let mut responses = Vec::new();
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(ref e) => match e.name() {
+                    QName(b"fsapiResponse") => responses.push(Self::from_reader(reader)?),
+                    _ => return Err(InternalError::Response(String::from("Unexpected start"))),
+                },
+                // </fsapiGetMultipleResponse> or </fsapiSetMultipleResponse>
+                Event::End(_) => break,
+                _ => return Err(InternalError::Response(String::from("Unexpected event"))),
+            }
+        }
+
+        Ok(responses)
+    }
+
+    fn from_reader(reader: &mut quick_xml::Reader<&[u8]>) -> Result<Self, InternalError> {
+        let mut node = None;
+        let mut status = None;
+        let mut value = None;
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(ref e) => match e.name() {
+                    QName(b"node") => {
+                        node = Some(Node::from(reader.read_text(e.name())?.to_string()))
+                    }
+                    QName(b"status") => {
+                        status = Some(ResponseStatus::parse(&reader.read_text(e.name())?))
+                    }
+                    QName(b"value") => {
+                        value = Some(Value::from_reader(reader)?);
+
+                        // Throw away </value>
+                        reader.read_event()?;
+                    }
+                    _ => return Err(InternalError::Response(String::from("Unexpected start"))),
+                },
+                Event::End(ref e) => match e.name() {
+                    QName(b"fsapiResponse") => break,
+                    _ => return Err(InternalError::Response(String::from("Unexpected end"))),
+                },
+                _ => return Err(InternalError::Response(String::from("Unexpected event"))),
+            }
+        }
+
+        match (node, status) {
+            (Some(node), Some(status)) => Ok(Self {
+                node,
+                status,
+                value,
+            }),
+            _ => Err(InternalError::Response(String::from(
+                "Incomplete node response",
+            ))),
+        }
+    }
+
+    /// Splits the response into the node and the outcome for that node
+    fn into_result(self, context: &ErrorContext) -> (Node, Result<Option<Value>, Error>) {
+        let result = self
+            .status
+            .check(&context.with_node(&self.node))
+            .map(|_| self.value);
+
+        (self.node, result)
+    }
+}
+
+impl Data {
+    fn from_reader(reader: &mut quick_xml::Reader<&[u8]>) -> Result<Option<Self>, InternalError> {
         match reader.read_event()? {
             Event::Start(ref e) => match e.name() {
                 QName(b"value") => Ok(Some(Self::Value(Value::from_reader(reader)?))),
@@ -1518,12 +922,14 @@ impl Data {
                         }
                     };
 
-                    Ok(Some(Data::Items(Item::items_from_reader(key, reader)?)))
+                    let (items, list_end) = Item::items_from_reader(key, reader)?;
+
+                    Ok(Some(Data::Items { items, list_end }))
                 }
                 QName(b"notify") => {
                     let node: Node = match e.attributes().next() {
                         Some(attribute) => {
-                            Node::try_from(String::from_utf8(attribute?.value.to_mut().to_vec())?)?
+                            Node::from(String::from_utf8(attribute?.value.to_mut().to_vec())?)
                         }
                         None => {
                             return Err(InternalError::Notify(String::from(
@@ -1536,7 +942,10 @@ impl Data {
                         node, reader,
                     )?)))
                 }
-                _ => panic!("Unknown data type"),
+                name => Err(InternalError::Response(format!(
+                    "Unknown data type: {}",
+                    String::from_utf8_lossy(name.as_ref())
+                ))),
             },
             Event::End(_) => Ok(None), // </fsapiResponse>
             _ => Err(InternalError::Response(String::from(
@@ -1551,24 +960,21 @@ impl Response {
         let mut reader = quick_xml::Reader::from_str(s);
         reader.trim_text(true);
 
-        let mut buf = Vec::new();
+        // Throw away <fsapiResponse>, multiple responses wrap
+        // an <fsapiResponse> per node in their own envelope
+        if let Event::Start(ref e) = reader.read_event()? {
+            if let QName(b"fsapiGetMultipleResponse" | b"fsapiSetMultipleResponse") = e.name() {
+                let responses = NodeResponse::responses_from_reader(&mut reader)?;
 
-        // Throw away <fsapiResponse>
-        reader.read_event()?;
+                return Ok(Response {
+                    status: ResponseStatus::Ok,
+                    data: Some(Data::Multiple(responses)),
+                });
+            }
+        }
 
         let status = if let Event::Start(ref e) = reader.read_event()? {
-            use ResponseStatus::*;
-            // TODO: Fix this match `to_string().as_str()`???
-            match reader.read_text(e.name())?.to_string().as_str() {
-                STATUS_FS_OK => Ok,
-                STATUS_FS_FAIL => Fail,
-                STATUS_FS_PACKET_BAD => PacketBad,
-                STATUS_FS_NODE_BLOCKED => NodeBlocked,
-                STATUS_FS_NODE_DOES_NOT_EXIST => NodeDoesNotExist,
-                STATUS_FS_TIMEOUT => Timeout,
-                STATUS_FS_LIST_END => ListEnd,
-                status => panic!("Unknow status: {status}"), // Err(InternalError::Generic(format!("Unknow status: {status}")))?,
-            }
+            ResponseStatus::parse(&reader.read_text(e.name())?)
         } else {
             return Err(InternalError::Response(String::from(
                 "Incorrect response format",
@@ -1576,7 +982,7 @@ impl Response {
         };
 
         let data: Option<Data> = if status == ResponseStatus::Ok {
-            Data::from_reader(&mut reader, &mut buf)?
+            Data::from_reader(&mut reader)?
         } else {
             None
         };
@@ -1584,3 +990,129 @@ impl Response {
         Ok(Response { status, data })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiple_response_keeps_status_per_node() {
+        let response = Response::from_str(
+            "<fsapiGetMultipleResponse>\
+                <fsapiResponse>\
+                    <node>netRemote.sys.audio.volume</node>\
+                    <value><u8>12</u8></value>\
+                    <status>FS_OK</status>\
+                </fsapiResponse>\
+                <fsapiResponse>\
+                    <node>netRemote.play.frequency</node>\
+                    <status>FS_NODE_BLOCKED</status>\
+                </fsapiResponse>\
+            </fsapiGetMultipleResponse>",
+        )
+        .unwrap();
+
+        let expected = vec![
+            NodeResponse {
+                node: Node::SysAudioVolume,
+                status: ResponseStatus::Ok,
+                value: Some(Value::U8(12)),
+            },
+            NodeResponse {
+                node: Node::PlayFrequency,
+                status: ResponseStatus::NodeBlocked,
+                value: None,
+            },
+        ];
+        assert_eq!(response.status, ResponseStatus::Ok);
+        assert_eq!(response.data, Some(Data::Multiple(expected)));
+    }
+
+    fn list_page(end: &str) -> String {
+        format!(
+            "<fsapiResponse>\
+                <status>FS_OK</status>\
+                <item key=\"0\"><field name=\"name\"><c8_array>One</c8_array></field></item>\
+                <item key=\"1\"><field name=\"name\"><c8_array>Two</c8_array></field></item>\
+                {end}\
+            </fsapiResponse>"
+        )
+    }
+
+    fn page_items(response: Response) -> (Vec<u32>, bool) {
+        match response.data {
+            Some(Data::Items { items, list_end }) => {
+                (items.iter().map(|item| item.key).collect(), list_end)
+            }
+            data => panic!("not a list page: {data:?}"),
+        }
+    }
+
+    #[test]
+    fn page_ending_with_listend_is_the_last() {
+        let response = Response::from_str(&list_page("<listend/>")).unwrap();
+
+        assert_eq!(page_items(response), (vec![0, 1], true));
+    }
+
+    #[test]
+    fn page_without_listend_has_more() {
+        let response = Response::from_str(&list_page("")).unwrap();
+
+        assert_eq!(page_items(response), (vec![0, 1], false));
+    }
+
+    fn value(value: &str) -> Result<Value, InternalError> {
+        let response = Response::from_str(&format!(
+            "<fsapiResponse><status>FS_OK</status><value>{value}</value></fsapiResponse>"
+        ))?;
+
+        match response.data {
+            Some(Data::Value(value)) => Ok(value),
+            data => panic!("not a value: {data:?}"),
+        }
+    }
+
+    #[test]
+    fn every_value_type_parses() {
+        assert_eq!(value("<u8>255</u8>").unwrap(), Value::U8(255));
+        assert_eq!(value("<u16>65535</u16>").unwrap(), Value::U16(65535));
+        assert_eq!(
+            value("<u32>4294967295</u32>").unwrap(),
+            Value::U32(u32::MAX)
+        );
+        assert_eq!(value("<s8>-128</s8>").unwrap(), Value::S8(-128));
+        assert_eq!(value("<s16>-32768</s16>").unwrap(), Value::S16(-32768));
+        assert_eq!(value("<s32>-1</s32>").unwrap(), Value::S32(-1));
+        assert_eq!(value("<e8>3</e8>").unwrap(), Value::E8(3));
+        assert_eq!(
+            value("<c8_array>Radio 4</c8_array>").unwrap(),
+            Value::Text(String::from("Radio 4"))
+        );
+        assert_eq!(
+            value("<array>00ff</array>").unwrap(),
+            Value::Array(String::from("00ff"))
+        );
+    }
+
+    #[test]
+    fn empty_text_parses() {
+        assert_eq!(value("<c8_array/>").unwrap(), Value::Text(String::new()));
+    }
+
+    #[test]
+    fn text_is_unescaped() {
+        assert_eq!(
+            value("<c8_array>Tom &amp; Jerry &lt;live&gt;</c8_array>").unwrap(),
+            Value::Text(String::from("Tom & Jerry <live>"))
+        );
+    }
+
+    #[test]
+    fn unknown_value_type_fails() {
+        assert!(matches!(
+            value("<u64>1</u64>"),
+            Err(InternalError::Value(_))
+        ));
+    }
+}
@@ -0,0 +1,920 @@
+//! Every node of the fsapi and a typed marker for each of them
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::RangeInclusive;
+
+use crate::{Error, ErrorContext, Value, ValueKind};
+pub use typed::*;
+
+mod typed;
+
+/// Declares every known node with its path and [`NodeDescriptor`]
+///
+/// The path is the one from the fsapi documentation,
+/// parsing is case-insensitive since radios send them in lowercase.
+macro_rules! nodes {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident = $path:literal, $access:ident($($kind:ident)?) $(.range($min:literal, $max:literal))?;
+    )*) => {
+        #[derive(Clone, Debug)]
+        pub enum Node {
+            $(
+                $(#[$meta])*
+                #[doc = concat!("PATH: \"", $path, "\"")]
+                $name,
+            )*
+
+            /// Any other node, with its path as sent by the radio
+            ///
+            /// Use it to reach nodes this crate does not know (yet),
+            /// e.g. `Node::from("netremote.multiroom.device.serverstatus")`.
+            /// Nothing is checked before sending requests for these nodes.
+            /// Paths are compared ignoring ASCII case, like the radio does.
+            Unknown(String),
+        }
+
+        impl Node {
+            /// Every known node, so everything but [`Node::Unknown`]
+            pub const ALL: &'static [Node] = &[$(Node::$name),*];
+
+            /// Path of the node as used by the fsapi
+            pub fn path(&self) -> &str {
+                match self {
+                    $(Node::$name => $path,)*
+                    Node::Unknown(path) => path,
+                }
+            }
+
+            /// Describes how this node can be accessed and what it returns
+            ///
+            /// Based on the documentation of every node, see the variants of [`Node`].
+            pub fn descriptor(&self) -> NodeDescriptor {
+                use ValueKind::*;
+
+                match self {
+                    $(Node::$name => NodeDescriptor::$access($($kind)?)$(.range($min, $max))?,)*
+                    Node::Unknown(_) => NodeDescriptor::unknown(),
+                }
+            }
+        }
+    };
+}
+
+nodes! {
+    // nav
+    /// Get the menu for the current mode
+    ///
+    /// To prevent overhead you could get the number of items by NavNumItems
+    ///
+    /// The -1 Parameter is the Start-Value, you could provide 4 to get all items with an index greater 4.
+    ///
+    /// The following field types are known:
+    ///
+    ///  1. folder
+    ///  2. mp3
+    ///  3. mp4
+    ///
+    /// Method: LIST_GET_NEXT
+    /// Returns: `Vec<Item(_)>`
+    NavList = "netRemote.nav.list", list();
+
+    /// Get the amount of entries for the current navigation-set.
+    ///
+    /// Method: GET
+    /// Returns: `Value::S32(_)`
+    NavNumItems = "netRemote.nav.numItems", read(S32);
+
+    /// Lists all favorite Radio Stations for the current mode
+    ///
+    /// Method: LIST_GET_NEXT
+    /// Returns: `Vec<Item<_>>`
+    NavPresets = "netRemote.nav.presets", list();
+
+    /// Search in the current navigation (see NavList)
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::Text(_)`
+    NavSearchTerm = "netRemote.nav.searchTerm", read_write(Text);
+
+    /// Enables the navigation in the menu (see NavList)
+    ///
+    /// Every change of the system mode, will disable the nav state to reset the current menu-position.
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U8([0,1])`
+    NavState = "netRemote.nav.state", flag();
+
+    /// While the device prepares the menu this node is set to 0, if the menu is ready it is set to 1.
+    ///
+    /// Busy menus are caused by mode-change or NavActionNavigate
+    ///
+    /// Method: GET
+    /// Returns: `Value::U8(_)`
+    NavStatus = "netRemote.nav.status", read(U8);
+
+    /// Returns the current navigation menu depth
+    ///
+    /// Method: GET
+    /// Returns: `Value::U8(_)`
+    NavDepth = "netRemote.nav.depth", read(U8);
+
+    // nav.action
+    /// Starts Scan for DAB Channels
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U8(_)`
+    NavActionDabScan = "netRemote.nav.action.dabScan", read_write(U8);
+
+    /// Selects the current menu entry (see NavList)
+    ///
+    /// For returning to the upper menu level just set it to -1.
+    ///
+    /// This function works only with folders (type=0), see NavList.
+    ///
+    /// Method: GET?, SET
+    /// Returns: ?
+    NavActionNavigate = "netRemote.nav.action.navigate", read_write(S32);
+
+    /// Selects an Menu Item (see netRemove.nav.list)
+    ///
+    /// This function works only on files (type > 0), see NavList.
+    ///
+    /// Method: GET?, SET
+    /// Returns: ?
+    NavActionSelectItem = "netRemote.nav.action.selectItem", read_write(U32);
+
+    /// Selects a  favorite Radio Stations (see NavPresets)
+    ///
+    /// Method: GET?, SET
+    /// Returns: ?
+    NavActionSelectPreset = "netRemote.nav.action.selectPreset", read_write(U32);
+
+    // play
+    /// Add the current radio stations to the favorites menu
+    ///
+    /// Method: GET?, SET
+    /// Returns: ?
+    PlayAddPreset = "netRemote.play.addPreset", read_write(U32);
+
+    /// Actions the player can do
+    /// 0: PlayControl: Play?
+    /// 1: PlayControl: Pause or Play?
+    /// 2: PlayControl: Next or Previous
+    /// 3: PlayControl: Next or Previous
+    /// 4:
+    /// 5:
+    /// 6:
+    /// 7:
+    /// 8:
+    /// 9:
+    /// 10:
+    /// 11: PlayAddPreset
+    ///
+    /// Method: GET
+    /// Returns: Value::U32(_),
+    PlayCaps = "netRemote.play.caps", read(U32);
+
+    /// Sets / Return the current play-controll mode
+    ///
+    /// 0: play/pause toggle
+    /// 1: ?
+    /// 2: ?
+    /// 3: next (song/station)
+    /// 4: previous (song/station)
+    /// ...: ?
+    ///
+    /// Wrong: Scope: Mediaplayer / Radio
+    /// Wrong: 1=Play; 2=Pause; 3=Next (song/station); 4=Previous (song/station)
+    ///
+    /// Method: GET/SET
+    /// Returns: Value::U8(_),
+    PlayControl = "netRemote.play.control", read_write(U8);
+
+    /// Method: GET
+    /// Returns: Value::Text(_),
+    PlayErrorStr = "netRemote.play.errorStr", read(Text);
+
+    /// Sets / Returns the current frequency for fm (in herz)
+    ///
+    /// Method: GET/SET
+    /// Returns: Value::U32(_),
+    PlayFrequency = "netRemote.play.frequency", read_write(U32);
+
+    /// Sets / Returns the current position in the track in milliseconds
+    ///
+    /// Keep in mind to get the max position by PlayDuration
+    ///
+    /// Method: GET/SET
+    /// Returns: Value::U32(_),
+    PlayPosition = "netRemote.play.position", read_write(U32);
+
+    /// Sets / Returns the current play-rate multiplier
+    ///
+    /// The value determines the speed of the playback.
+    /// It can be in the range of -127 to 127 where the negative part plays the current track backwards.
+    ///
+    /// Method: GET/SET
+    /// Returns: Value::S8(_),
+    PlayRate = "netRemote.play.rate", read_write(S8).range(-127, 127);
+
+    /// Sets / Returns the current play-rate multiplier
+    ///
+    /// Method: GET/SET
+    /// Returns: Value::U8([0,1]),
+    PlayRepeat = "netRemote.play.repeat", flag();
+
+    /// Returns  whether or not scrobble is enabled or not
+    ///
+    /// Method: GET/SET
+    /// Returns: Value::U8([0,1]),
+    PlayScrobble = "netRemote.play.scrobble", flag();
+
+    /// Sets / Returns whether or not shuffle is enabled or not (1/0)
+    ///
+    /// Method: GET/SET
+    /// Returns: Value::U8([0,1]),
+    PlayShuffle = "netRemote.play.shuffle", flag();
+
+    /// Sets / Returns whether or not shuffle is enabled or not (1/0)
+    ///
+    /// Is part of the new api version
+    ///
+    /// Method: GET/SET
+    /// Returns: Value::U8([0,1]),
+    PlayShuffleStatus = "netRemote.play.shuffleStatus", flag();
+
+    /// Returns the signal strenght of the current medium
+    ///
+    /// Method: GET
+    /// Returns: Value::U8(_),
+    PlaySignalStrength = "netRemote.play.signalStrength", read(U8);
+
+    /// Returns status of the player
+    ///
+    /// 1=buffering/loading, 2=playing, 3=paused
+    ///
+    /// Method: GET
+    /// Returns: Value::U8([0,3]),
+    PlayStatus = "netRemote.play.status", read(U8);
+
+    // play.info
+    /// Returns  the name of the artist of the current song
+    ///
+    /// Method: GET
+    /// Returns: Value::Text(_),
+    PlayInfoAlbum = "netRemote.play.info.album", read(Text);
+
+    /// Returns the name of the album of the current song
+    ///
+    /// Method: GET
+    /// Returns: Value::Text(_),
+    PlayInfoArtist = "netRemote.play.info.artist", read(Text);
+
+    /// Returns the duration for the track in milliseconds
+    ///
+    /// Method: GET
+    /// Returns: Value::U32(_),
+    PlayInfoDuration = "netRemote.play.info.duration", read(U32);
+
+    /// Returns the uri of an image representing the current song / station
+    ///
+    /// Method: GET
+    /// Returns: Value::Text(_),
+    PlayInfoGraphicUri = "netRemote.play.info.graphicUri", read(Text);
+
+    /// Returns the first line of the display
+    ///
+    /// Method: GET
+    /// Returns: Value::Text(_),
+    PlayInfoName = "netRemote.play.info.name", read(Text);
+
+    /// Returns  the second line of the display
+    ///
+    /// Method: GET
+    /// Returns: Value::Text(_),
+    PlayInfoText = "netRemote.play.info.text", read(Text);
+
+    // play.serviceIds,
+    /// Returns DAB Ensemble Identifier (decimal notation)
+    /// Note: commonly used in Hex notation.
+    ///
+    /// Method: GET
+    /// Returns: Value::U16(_),
+    PlayServiceIdsDabEnsambleId = "netRemote.play.serviceIds.dabEnsembleId", read(U16);
+
+    /// Returns the DAB Service Component Identifier (decimal notation)
+    /// Note: Nearly always 0 for audio services - Secondary Component services will
+    /// have a different value
+    ///
+    /// Method: GET
+    /// Returns: Value::U8(_),
+    PlayServiceIdsDabScids = "netRemote.play.serviceIds.dabScids", read(U8);
+
+    /// Returns DAB Service Identifier (decimal notation)
+    /// Note: commonly used in Hex notation.
+    ///
+    /// Method: GET
+    /// Returns: Value::U32(_),
+    PlayServiceIdsDabServiceId = "netRemote.play.serviceIds.dabServiceId", read(U32);
+
+    /// Returns Extended Country Code (decimal notation) as defined in ETSI TS 101 756
+    /// Note: commonly used in Hex notation.
+    ///
+    /// Method: GET
+    /// Returns: Value::U8(_),
+    PlayServiceIdsEcc = "netRemote.play.serviceIds.ecc", read(U8);
+
+    /// Returns RDS Programme Identification code
+    ///
+    /// Method: GET
+    /// Returns: Value::U16(_),
+    PlayServiceIdsfmRdsPi = "netRemote.play.serviceIds.fmRdsPi", read(U16);
+
+    // sys
+    /// Retrieve/set the radio user interface language
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U32(_)`
+    SysLang = "netRemote.sys.lang", read_write(U32);
+
+    /// Sets / Returns the current operation mode
+    ///
+    /// see SysCapsValidModes for valid operation modes
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U32(_)`
+    SysMode = "netRemote.sys.mode", read_write(U32);
+
+    /// Sets / Returns the current power state
+    ///
+    /// If device returns from standby it will only auto-continue to play in radio-modes.
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U32([0,1])``
+    SysPower = "netRemote.sys.power", flag();
+
+    /// Sets / Returns the Time till Sleep in seconds (0 = No Sleep) [works with Firmware V2.9.10 but not with V2.6.17]
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U32(_)``
+    SysSleep = "netRemote.sys.sleep", read_write(U32);
+
+    /// ???
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U8([0,1])``
+    SysState = "netRemote.sys.state", flag();
+
+    // sys.audio
+    /// Sets / Returns the first value for costum eq-settings (Bass)
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::S16([-7, 7])`
+    SysAudioEqCustomParam0 = "netRemote.sys.audio.eqCustom.param0", read_write(S16).range(-7, 7);
+
+    /// Sets/Returns the first value for costum eq-settings (Treble)
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::S16([-7, 7])`
+    SysAudioEqCustomParam1 = "netRemote.sys.audio.eqCustom.param1", read_write(S16).range(-7, 7);
+
+    /// Sets / Returns whether or not loudness is activated
+    ///
+    /// This function is only available if costum eq is active
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U8([0, 1])`
+    SysAudioEqLoudness = "netRemote.sys.audio.eqLoudness", flag();
+
+    /// Sets / Returns the number of the selected eq-presets
+    ///
+    /// see: SysCapsEqPresets for valid presets.
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U8(_)`
+    SysAudioEqPreset = "netRemote.sys.audio.eqPreset", read_write(U8);
+
+    /// Sets / Returns whether or not device is muted
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U8([0, 1])`
+    SysAudioMute = "netRemote.sys.audio.mute", flag();
+
+    /// Sets / Returns the volume of the device
+    ///
+    /// Method: GET, SET
+    /// Returns: `Value::U8([0, 20])`
+    SysAudioVolume = "netRemote.sys.audio.volume", read_write(U8);
+
+    // sys.caps
+    /// Fetch the list of available time sources
+    ///
+    /// Method: LIST_GET_NEXT
+    /// Returns: `Items(_)`
+    SysCapsClockSourceList = "netRemote.sys.caps.clockSourceList", list();
+
+    /// Lists available dab-frequencies
+    ///
+    /// Method: LIST_GET_NEXT
+    /// Returns: `Items(_)`
+    SysCapsDabFreqList = "netRemote.sys.caps.dabFreqList", list();
+
+    /// Lists setted modes for the eq
+    ///
+    /// Method: LIST_GET_NEXT
+    /// Returns: `Items(_)`
+    SysCapsEqBands = "netRemote.sys.caps.eqBands", list();
+
+    /// Lists available eq-presets
+    ///
+    /// Method: LIST_GET_NEXT
+    /// Returns: `Items(_)`
+    SysCapsEqPresets = "netRemote.sys.caps.eqPresets", list();
+
+    /// Returns the lowest available fm-frequency
+    ///
+    /// Method: GET
+    /// Returns: `Value::U32(_)`
+    SysCapsFmFreqRangeLower = "netRemote.sys.caps.fmFreqRange.lower", read(U32);
+
+    /// Returns the size of the steps for increasing / decreasing the frequency
+    ///
+    /// Method: GET
+    /// Returns: `Value::U32(_)`
+    SysCapsFmFreqRangeStepSize = "netRemote.sys.caps.fmFreqRange.stepSize", read(U32);
+
+    /// Returns the highest available fm-frequency
+    ///
+    /// Method: GET
+    /// Returns: `Value::U32(_)`
+    SysCapsFmFreqRangeUpper = "netRemote.sys.caps.fmFreqRange.upper", read(U32);
+
+    /// Lists valid operations modes
+    /// Method: LIST_GET_NEXT
+    /// Returns: `Items(_)`
+    SysCapsValidModes = "netRemote.sys.caps.validModes", list();
+
+    /// Returns the max volume level
+    ///
+    /// Method: GET
+    /// Returns: `Value::U8(_)`
+    SysCapsVolumeSteps = "netRemote.sys.caps.volumeSteps", read(U8);
+
+    // sys.clock
+    /// Fetch/set daylight saving setting
+    ///
+    /// Method: SET/GET
+    /// Returns: `Value::U8([0,1])`
+    SysClockDst = "netRemote.sys.clock.dst", flag();
+
+    /// Returns the local Date in XML-RPC date format ( 20150914 = 2015-09-14)
+    ///
+    /// Method: GET
+    /// Returns: `Value::Text(String)`
+    SysClockLocalDate = "netRemote.sys.clock.localDate", read(Text);
+
+    /// Returns the local time in XML-RPC date format ( 093327 = 09:33:27)
+    ///
+    /// Method: GET
+    /// Returns: `Value::Text(String)`
+    SysClockLocalTime = "netRemote.sys.clock.localTime", read(Text);
+
+    /// Set the clock to 12h or 24 hour mode
+    ///
+    /// Values:
+    /// - 0: 12h
+    /// - 1: 24h
+    ///
+    /// Method: SET/GET
+    /// Returns: `Value::U8([0,1])`
+    SysClockMode = "netRemote.sys.clock.mode", flag();
+
+    /// Fetch/set the time source.
+    ///
+    /// Manual: 0
+    /// DAB: 1
+    /// FM: 2
+    /// ???: 3
+    /// Internet: 4
+    ///
+    /// Valid values can be fetched with `SysCapsClockSourceList`.
+    ///
+    /// Method: SET/GET
+    /// Returns: `Value::U8(_)`
+    SysClockSource = "netRemote.sys.clock.source", read_write(U8);
+
+    /// Get/set the offset of the local time compared to UTC.
+    ///
+    /// Valid values can be fetched with `SysCapsUtcSettingsList`.
+    ///
+    /// Method: SET/GET
+    /// Returns: `Value::U8(_)`
+    SysClockUtcOffset = "netRemote.sys.clock.utcOffset", read_write(U8);
+
+    // sys.cfg
+    /// TODO: description
+    ///
+    /// Is part of the new API Version.
+    ///
+    /// Method: SET/GET
+    /// Returns: `Value::U8(_)`
+    SysCfgIrAutoPlayFlag = "netRemote.sys.cfg.irAutoPlayFlag", read_write(U8);
+
+    // sys.info
+    /// Sets/ Returns the Network-Name of the Device
+    ///
+    /// Method: SET/GET
+    /// Returns: `Value::Text(String)`
+    SysInfoFriendlyName = "netRemote.sys.info.friendlyName", read_write(Text);
+
+    /// Returns unique? ID Radio-ID
+    ///
+    /// Method: GET
+    /// Returns: `Value::Text(String)`
+    SysInfoRadioId = "netRemote.sys.info.radioId", read(Text);
+
+    /// Change the PIN used to access the radio API.
+    ///
+    /// Method: SET
+    /// Value: pin
+    SysInfoRadioPin = "netRemote.sys.info.radioPin", write(Text);
+
+    /// Returns Image-Version String
+    ///
+    /// Method: GET
+    /// Returns: `Value::Text(String)`
+    SysInfoVersion = "netRemote.sys.info.version", read(Text);
+
+    /// Get or Set the Name of the Device which is remote-controling the radio
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::Text(String)`
+    SysInfoControlName = "netRemote.sys.info.controllerName", read_write(Text);
+
+    // sys.isu
+    /// TODO: Descscription
+    ///
+    /// Value:
+    ///     2 = search for updates
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U8(_)`
+    SysIsuControl = "netRemote.sys.isu.control", read_write(U8);
+
+    /// Shows the update process, default 0
+    ///
+    /// while searching: 1
+    /// after searching: 0
+    /// ???: 3
+    ///
+    /// Method: GET
+    /// Returns: `Value::U8([0,1])`
+    SysIsuState = "netRemote.sys.isu.state", read(U8);
+
+    // sys.net
+    // sys.net.ipConfig
+    /// Returns/sets? the IP address for the connected network
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U32(_)`
+    SysNetIpConfigAddress = "netRemote.sys.net.ipConfig.address", read_write(U32);
+
+    /// Returns if DHCP is enabled for the connected network
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U8([0,1])`
+    SysNetIpConfigDhcp = "netRemote.sys.net.ipConfig.dhcp", flag();
+
+    /// Returns the primary dns for the connected network
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U32(_)`
+    SysNetIpConfigDnsPrimary = "netRemote.sys.net.ipConfig.dnsPrimary", read_write(U32);
+
+    /// Returns the secundary dns for the connected network
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U32(_)`
+    SysNetIpConfigDnsSecundary = "netRemote.sys.net.ipConfig.dnsSecondary", read_write(U32);
+
+    /// Returns the default gateway for the connected network
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U32(_)`
+    SysNetIpConfigGateway = "netRemote.sys.net.ipConfig.gateway", read_write(U32);
+
+    /// Returns the subnet masky for the connected network
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U32(_)`
+    SysNetIpConfigSubnetMask = "netRemote.sys.net.ipConfig.subnetMask", read_write(U32);
+
+    /// If set to 1 network connection is not disconnected in standby
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U8([0,1])`
+    SysNetIpConfigKeepConnected = "netRemote.sys.net.ipConfig.keepConnected", flag();
+
+    /// Returns the NIC Status of the Ethernet Device
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U8(_)`
+    SysNetWiredInterfaceEnable = "netRemote.sys.net.wired.interfaceEnable", read_write(U8);
+
+    /// Returns the MAC Address of the Ethernet Device
+    ///
+    /// Method: GET
+    /// Returns: `Value::Text(_)`
+    SysNetWiredInterfaceMacAddress = "netRemote.sys.net.wired.macAddress", read(Text);
+
+    /// Returns the SSID of the connected WIFI network
+    ///
+    /// Method: GET
+    ///
+    /// Returns: `Value::Text(_)`
+    SysNetWlanConnectedSSID = "netRemote.sys.net.wlan.connectedSSID", read(Text);
+
+    /// Returns the NIC Status of the WIFI Device
+    ///
+    /// Method: GET/SET
+    /// Returns: `Value::U8(_)`
+    SysNetWlanInterfaceEnable = "netRemote.sys.net.wlan.interfaceEnable", read_write(U8);
+
+    /// Returns the MAC Address of the WIFI Device
+    ///
+    /// Method: GET
+    /// Returns: `Value::Text(_)`
+    SysNetWlanInterfaceMacAddress = "netRemote.sys.net.wlan.macAddress", read(Text);
+
+    /// Returns the Signal Strenght of the connected WIFI network
+    ///
+    /// Method: GET
+    /// Returns: `Value::u8(_)`
+    SysNetWlanRssi = "netRemote.sys.net.wlan.rssi", read(U8);
+
+    /// Returns the ??? of the connected WIFI network
+    ///
+    /// Method: GET
+    /// Returns: `Value::u8(_)`
+    SysNetWlanSetAuthType = "netRemote.sys.net.wlan.setAuthType", read(U8);
+
+    /// Returns the encryption type of the connected WIFI network
+    ///
+    /// Method: GET
+    /// Returns: `Value::u8(_)`
+    SysNetWlanSetEncType = "netRemote.sys.net.wlan.setEncType", read(U8);
+
+    /// Fetch the public RSA key that is used to encrypt the Wifi password before
+    /// sending it to the radio.
+    ///
+    /// Method: GET
+    /// Returns: `Value::Text(_)`
+    SysRsaPublicKey = "netRemote.sys.rsa.publicKey", read(Text);
+
+    /// Check the status of the RSA key generation.
+    /// - 0: Generating
+    /// - 1: Ready
+    ///
+    /// Method: GET
+    /// Returns: `Value::u32(_)`
+    SysRsaStatus = "netRemote.sys.rsa.status", read(U32);
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path())
+    }
+}
+
+/// Parses a node path, paths that are not known become [`Node::Unknown`]
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Node::Unknown(path), Node::Unknown(other)) => path.eq_ignore_ascii_case(other),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Node {}
+
+/// Consistent with [`PartialEq`], unknown paths hash the same in any case
+impl Hash for Node {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let Node::Unknown(path) = self {
+            path.to_ascii_lowercase().hash(state);
+        }
+    }
+}
+
+impl From<String> for Node {
+    fn from(value: String) -> Self {
+        Node::ALL
+            .iter()
+            .find(|node| node.path().eq_ignore_ascii_case(&value))
+            .cloned()
+            .unwrap_or(Node::Unknown(value))
+    }
+}
+
+impl From<&str> for Node {
+    fn from(value: &str) -> Self {
+        Node::from(value.to_string())
+    }
+}
+
+impl std::str::FromStr for Node {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Node::from(s))
+    }
+}
+
+/// What can be done with a node and what it returns
+///
+/// See [`Node::descriptor`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeDescriptor {
+    /// Type of the value returned by a `GET`, `None` for lists
+    pub kind: Option<ValueKind>,
+
+    /// Can be read with `GET`
+    pub readable: bool,
+
+    /// Can be changed with `SET`
+    pub writable: bool,
+
+    /// Can be read with `LIST_GET_NEXT`
+    pub listable: bool,
+
+    /// Valid values if known
+    pub range: Option<RangeInclusive<i64>>,
+}
+
+impl NodeDescriptor {
+    /// A node that can only be read with `GET`
+    const fn read(kind: ValueKind) -> Self {
+        Self {
+            kind: Some(kind),
+            readable: true,
+            writable: false,
+            listable: false,
+            range: None,
+        }
+    }
+
+    /// A node that can be read with `GET` and changed with `SET`
+    const fn read_write(kind: ValueKind) -> Self {
+        Self {
+            writable: true,
+            ..Self::read(kind)
+        }
+    }
+
+    /// A node that can only be changed with `SET`
+    const fn write(kind: ValueKind) -> Self {
+        Self {
+            readable: false,
+            ..Self::read_write(kind)
+        }
+    }
+
+    /// A node that can only be read with `LIST_GET_NEXT`
+    const fn list() -> Self {
+        Self {
+            kind: None,
+            readable: false,
+            writable: false,
+            listable: true,
+            range: None,
+        }
+    }
+
+    /// A node without a known descriptor, nothing is checked
+    const fn unknown() -> Self {
+        Self {
+            kind: None,
+            readable: true,
+            writable: true,
+            listable: true,
+            range: None,
+        }
+    }
+
+    /// A `0`/`1` flag that can be read and changed
+    const fn flag() -> Self {
+        Self::read_write(ValueKind::U8).range(0, 1)
+    }
+
+    const fn range(self, min: i64, max: i64) -> Self {
+        Self {
+            range: Some(min..=max),
+            ..self
+        }
+    }
+
+    /// Whether a value of type `found` is acceptable for this node
+    ///
+    /// Radios don't agree on the exact integer type of a node,
+    /// so any integer is accepted where an integer is expected.
+    pub fn accepts(&self, found: ValueKind) -> bool {
+        match self.kind {
+            Some(expected) if expected.is_integer() => found.is_integer(),
+            Some(expected) => expected == found,
+            None => false,
+        }
+    }
+}
+
+impl Node {
+    pub(crate) fn check_readable(&self) -> Result<NodeDescriptor, Error> {
+        let descriptor = self.descriptor();
+        match descriptor.readable {
+            true => Ok(descriptor),
+            false => Err(Error::NotReadable(self.clone())),
+        }
+    }
+
+    pub(crate) fn check_writable(&self, param: &str) -> Result<(), Error> {
+        let descriptor = self.descriptor();
+        if !descriptor.writable {
+            return Err(Error::ReadOnly(self.clone()));
+        }
+
+        match (descriptor.range, param.parse::<i64>()) {
+            (Some(range), Ok(value)) if !range.contains(&value) => Err(Error::OutOfRange {
+                node: self.clone(),
+                value,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_listable(&self) -> Result<(), Error> {
+        match self.descriptor().listable {
+            true => Ok(()),
+            false => Err(Error::NotListable(self.clone())),
+        }
+    }
+
+    /// Checks the type of a value returned by a `GET` of this node
+    pub(crate) fn check_value(&self, value: Value, context: &ErrorContext) -> Result<Value, Error> {
+        let descriptor = self.descriptor();
+        match (descriptor.kind, descriptor.accepts(value.kind())) {
+            (Some(expected), false) => Err(Error::UnexpectedValue {
+                expected,
+                found: value.kind(),
+                context: Some(context.with_node(self)),
+            }),
+            _ => Ok(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_node_round_trips() {
+        for node in Node::ALL {
+            assert_eq!(&Node::from(node.to_string()), node);
+            assert_eq!(&Node::from(node.path().to_lowercase()), node);
+            assert_eq!(&Node::from(node.path().to_uppercase()), node);
+        }
+    }
+
+    #[test]
+    fn paths_are_unique() {
+        let mut paths: Vec<String> = Node::ALL.iter().map(|n| n.path().to_lowercase()).collect();
+        paths.sort();
+        paths.dedup();
+
+        assert_eq!(paths.len(), Node::ALL.len());
+    }
+
+    #[test]
+    fn unknown_node_round_trips() {
+        let path = "netremote.multiroom.device.serverstatus";
+        let node = Node::from(path);
+
+        assert_eq!(node, Node::Unknown(path.to_string()));
+        assert_eq!(node.to_string(), path);
+    }
+
+    #[test]
+    fn unknown_nodes_ignore_case() {
+        let node = Node::from("netRemote.multiroom.x");
+        let lowercase = Node::from("netremote.multiroom.x");
+
+        assert_eq!(node, lowercase);
+        assert_ne!(node, Node::from("netremote.multiroom.y"));
+
+        let values = std::collections::HashMap::from([(lowercase, 1)]);
+        assert_eq!(values.get(&node), Some(&1));
+    }
+}
@@ -0,0 +1,269 @@
+use std::time::Duration;
+
+use crate::{Error, Node, Value};
+
+/// A node with a known Rust type
+///
+/// Implemented by the marker types in [`crate::node`], use them with
+/// [`FsApi::get_typed`](crate::FsApi::get_typed):
+///
+/// ```no_run
+/// # async fn example(fsapi: fsapi::FsApi) -> Result<(), fsapi::Error> {
+/// use fsapi::node::SysAudioVolume;
+///
+/// let volume: u8 = fsapi.get_typed::<SysAudioVolume>().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait TypedNode {
+    /// The node this marker stands for
+    const NODE: Node;
+
+    /// The Rust type of the value of the node
+    type Type;
+
+    /// Converts the value returned by the fsapi server
+    fn decode(value: Value) -> Result<Self::Type, Error>;
+}
+
+/// A [`TypedNode`] that can be changed with `SET`
+pub trait WritableNode: TypedNode {
+    /// Converts a Rust value to the value expected by the fsapi server
+    fn encode(value: Self::Type) -> Value;
+}
+
+/// Conversion between a [`Value`] and a Rust type
+trait Codec {
+    type Type;
+
+    fn decode(value: Value) -> Result<Self::Type, Error>;
+
+    fn encode(value: Self::Type) -> Value;
+}
+
+macro_rules! integer_codec {
+    ($($codec:ident: $ty:ty => $accessor:ident),* $(,)?) => {
+        $(
+            struct $codec;
+
+            impl Codec for $codec {
+                type Type = $ty;
+
+                fn decode(value: Value) -> Result<$ty, Error> {
+                    value.$accessor()
+                }
+
+                fn encode(value: $ty) -> Value {
+                    value.into()
+                }
+            }
+        )*
+    };
+}
+
+integer_codec! {
+    U8: u8 => as_u8,
+    U16: u16 => as_u16,
+    U32: u32 => as_u32,
+    I8: i8 => as_i8,
+    I16: i16 => as_i16,
+    I32: i32 => as_i32,
+}
+
+/// `0`/`1` flags
+struct Flag;
+
+impl Codec for Flag {
+    type Type = bool;
+
+    fn decode(value: Value) -> Result<bool, Error> {
+        value.as_bool()
+    }
+
+    fn encode(value: bool) -> Value {
+        value.into()
+    }
+}
+
+struct Text;
+
+impl Codec for Text {
+    type Type = String;
+
+    fn decode(value: Value) -> Result<String, Error> {
+        value.into_text()
+    }
+
+    fn encode(value: String) -> Value {
+        value.into()
+    }
+}
+
+/// A duration sent as a number of seconds
+struct Secs;
+
+impl Codec for Secs {
+    type Type = Duration;
+
+    fn decode(value: Value) -> Result<Duration, Error> {
+        Ok(Duration::from_secs(value.as_u32()?.into()))
+    }
+
+    fn encode(value: Duration) -> Value {
+        Value::U32(value.as_secs().try_into().unwrap_or(u32::MAX))
+    }
+}
+
+/// A duration sent as a number of milliseconds
+struct Millis;
+
+impl Codec for Millis {
+    type Type = Duration;
+
+    fn decode(value: Value) -> Result<Duration, Error> {
+        Ok(Duration::from_millis(value.as_u32()?.into()))
+    }
+
+    fn encode(value: Duration) -> Value {
+        Value::U32(value.as_millis().try_into().unwrap_or(u32::MAX))
+    }
+}
+
+macro_rules! typed_nodes {
+    ($($name:ident: $ty:ty = $codec:ident $(, $writable:ident)?;)*) => {
+        $(
+            #[doc = concat!("Typed marker for [`Node::", stringify!($name), "`]")]
+            #[derive(Clone, Copy, Debug)]
+            pub struct $name;
+
+            impl TypedNode for $name {
+                const NODE: Node = Node::$name;
+
+                type Type = $ty;
+
+                fn decode(value: Value) -> Result<$ty, Error> {
+                    $codec::decode(value)
+                }
+            }
+
+            $(typed_nodes!(@$writable $name: $ty = $codec);)?
+        )*
+    };
+    (@writable $name:ident: $ty:ty = $codec:ident) => {
+        impl WritableNode for $name {
+            fn encode(value: $ty) -> Value {
+                $codec::encode(value)
+            }
+        }
+    };
+}
+
+// List nodes have no value and `SysInfoRadioPin` can not be read,
+// so they don't get a marker.
+typed_nodes! {
+    // nav
+    NavNumItems: i32 = I32;
+    NavSearchTerm: String = Text, writable;
+    NavState: bool = Flag, writable;
+    NavStatus: u8 = U8;
+    NavDepth: u8 = U8;
+
+    // nav.action
+    NavActionDabScan: u8 = U8, writable;
+    NavActionNavigate: i32 = I32, writable;
+    NavActionSelectItem: u32 = U32, writable;
+    NavActionSelectPreset: u32 = U32, writable;
+
+    // play
+    PlayAddPreset: u32 = U32, writable;
+    PlayCaps: u32 = U32;
+    PlayControl: u8 = U8, writable;
+    PlayErrorStr: String = Text;
+    PlayFrequency: u32 = U32, writable;
+    PlayPosition: Duration = Millis, writable;
+    PlayRate: i8 = I8, writable;
+    PlayRepeat: bool = Flag, writable;
+    PlayScrobble: bool = Flag, writable;
+    PlayShuffle: bool = Flag, writable;
+    PlayShuffleStatus: bool = Flag, writable;
+    PlaySignalStrength: u8 = U8;
+    PlayStatus: u8 = U8;
+
+    // play.info
+    PlayInfoAlbum: String = Text;
+    PlayInfoArtist: String = Text;
+    PlayInfoDuration: Duration = Millis;
+    PlayInfoGraphicUri: String = Text;
+    PlayInfoName: String = Text;
+    PlayInfoText: String = Text;
+
+    // play.serviceIds
+    PlayServiceIdsDabEnsambleId: u16 = U16;
+    PlayServiceIdsDabScids: u8 = U8;
+    PlayServiceIdsDabServiceId: u32 = U32;
+    PlayServiceIdsEcc: u8 = U8;
+    PlayServiceIdsfmRdsPi: u16 = U16;
+
+    // sys
+    SysLang: u32 = U32, writable;
+    SysMode: u32 = U32, writable;
+    SysPower: bool = Flag, writable;
+    SysSleep: Duration = Secs, writable;
+    SysState: bool = Flag, writable;
+
+    // sys.audio
+    SysAudioEqCustomParam0: i16 = I16, writable;
+    SysAudioEqCustomParam1: i16 = I16, writable;
+    SysAudioEqLoudness: bool = Flag, writable;
+    SysAudioEqPreset: u8 = U8, writable;
+    SysAudioMute: bool = Flag, writable;
+    SysAudioVolume: u8 = U8, writable;
+
+    // sys.caps
+    SysCapsFmFreqRangeLower: u32 = U32;
+    SysCapsFmFreqRangeStepSize: u32 = U32;
+    SysCapsFmFreqRangeUpper: u32 = U32;
+    SysCapsVolumeSteps: u8 = U8;
+
+    // sys.clock
+    SysClockDst: bool = Flag, writable;
+    SysClockLocalDate: String = Text;
+    SysClockLocalTime: String = Text;
+    SysClockMode: bool = Flag, writable;
+    SysClockSource: u8 = U8, writable;
+    SysClockUtcOffset: u8 = U8, writable;
+
+    // sys.cfg
+    SysCfgIrAutoPlayFlag: u8 = U8, writable;
+
+    // sys.info
+    SysInfoFriendlyName: String = Text, writable;
+    SysInfoRadioId: String = Text;
+    SysInfoVersion: String = Text;
+    SysInfoControlName: String = Text, writable;
+
+    // sys.isu
+    SysIsuControl: u8 = U8, writable;
+    SysIsuState: u8 = U8;
+
+    // sys.net
+    SysNetIpConfigAddress: u32 = U32, writable;
+    SysNetIpConfigDhcp: bool = Flag, writable;
+    SysNetIpConfigDnsPrimary: u32 = U32, writable;
+    SysNetIpConfigDnsSecundary: u32 = U32, writable;
+    SysNetIpConfigGateway: u32 = U32, writable;
+    SysNetIpConfigSubnetMask: u32 = U32, writable;
+    SysNetIpConfigKeepConnected: bool = Flag, writable;
+    SysNetWiredInterfaceEnable: u8 = U8, writable;
+    SysNetWiredInterfaceMacAddress: String = Text;
+    SysNetWlanConnectedSSID: String = Text;
+    SysNetWlanInterfaceEnable: u8 = U8, writable;
+    SysNetWlanInterfaceMacAddress: String = Text;
+    SysNetWlanRssi: u8 = U8;
+    SysNetWlanSetAuthType: u8 = U8;
+    SysNetWlanSetEncType: u8 = U8;
+
+    // sys.rsa
+    SysRsaPublicKey: String = Text;
+    SysRsaStatus: u32 = U32;
+}
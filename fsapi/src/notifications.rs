@@ -0,0 +1,50 @@
+use std::future::Future;
+
+use futures_util::stream::{self, Stream, StreamExt};
+
+use crate::{Backoff, Error, Notification};
+
+/// Polls `GET_NOTIFIES` until the stream is dropped
+///
+/// `FS_TIMEOUT`s (`Ok(None)`) are polled again right away, network errors
+/// are retried with `backoff`. The stream ends after any other error,
+/// or once the backoff gives up.
+///
+/// The stream is boxed so it can be used without pinning it first.
+pub(crate) fn poll_notifications<P, Fut>(
+    poll: P,
+    backoff: Backoff,
+) -> impl Stream<Item = Result<Notification, Error>> + Unpin + Send + 'static
+where
+    P: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<Option<Vec<Notification>>, Error>> + Send,
+{
+    stream::unfold(Some(poll), move |poll| async move {
+        let poll = poll?;
+        let mut attempt = 0;
+
+        loop {
+            match poll().await {
+                Ok(Some(notifications)) => {
+                    return Some((notifications.into_iter().map(Ok).collect(), Some(poll)))
+                }
+                Ok(None) => attempt = 0,
+                Err(err) if is_network_error(&err) => match backoff.delay(attempt) {
+                    Some(delay) => {
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                    }
+                    None => return Some((vec![Err(err)], None)),
+                },
+                Err(err) => return Some((vec![Err(err)], None)),
+            }
+        }
+    })
+    .flat_map(stream::iter)
+    .boxed()
+}
+
+/// The radio could not be reached, which might be fixed by trying again
+fn is_network_error(err: &Error) -> bool {
+    matches!(err, Error::Http { .. }) && !err.is_invalid_session()
+}
@@ -0,0 +1,188 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use futures_util::stream::Stream;
+use tokio::sync::{watch, Mutex};
+
+use crate::notifications::poll_notifications;
+use crate::{Backoff, Error, FsApi, Item, Node, Notification, SessionID};
+
+/// Keeps a session with the radio alive
+///
+/// A radio only allows one session at a time, so any other client
+/// (like the app of the vendor) that creates a session invalidates ours.
+/// The manager notices that and creates a new session, with a [`Backoff`]
+/// between attempts.
+///
+/// Cloning is cheap and every clone shares the same session.
+/// The session is deleted on [`SessionManager::shutdown`],
+/// or when the last clone is dropped inside a tokio runtime.
+#[derive(Clone, Debug)]
+pub struct SessionManager {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    fsapi: FsApi,
+    backoff: Backoff,
+
+    /// Current session, `None` after a shutdown
+    session: watch::Sender<Option<SessionID>>,
+
+    /// Held while a new session is created, so concurrent
+    /// failures only create one new session
+    renew: Mutex<()>,
+}
+
+impl SessionManager {
+    /// Creates a session with the default [`Backoff`]
+    pub async fn new(fsapi: FsApi) -> Result<Self, Error> {
+        Self::with_backoff(fsapi, Backoff::default()).await
+    }
+
+    /// Creates a session, retrying failed attempts with `backoff`
+    pub async fn with_backoff(fsapi: FsApi, backoff: Backoff) -> Result<Self, Error> {
+        let session_id = create_session(&fsapi, backoff).await?;
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                fsapi,
+                backoff,
+                session: watch::channel(Some(session_id)).0,
+                renew: Mutex::new(()),
+            }),
+        })
+    }
+
+    /// The client used for every request of this session
+    pub fn fsapi(&self) -> &FsApi {
+        &self.inner.fsapi
+    }
+
+    /// The current session, `None` after [`SessionManager::shutdown`]
+    pub fn session_id(&self) -> Option<SessionID> {
+        *self.inner.session.borrow()
+    }
+
+    /// Watch for new sessions
+    ///
+    /// The receiver is notified every time the session is re-created,
+    /// it holds `None` once the manager was shut down.
+    pub fn subscribe(&self) -> watch::Receiver<Option<SessionID>> {
+        self.inner.session.subscribe()
+    }
+
+    /// Runs `request` with the current session
+    ///
+    /// When the radio reports that the session no longer exists
+    /// a new session is created and `request` runs once more.
+    pub async fn with_session<T, F, Fut>(&self, request: F) -> Result<T, Error>
+    where
+        F: Fn(SessionID) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let session_id = self.session_id().ok_or(Error::SessionClosed)?;
+
+        match request(session_id).await {
+            Err(err) if err.is_invalid_session() => {
+                let session_id = self.renew(session_id).await?;
+                request(session_id).await
+            }
+            result => result,
+        }
+    }
+
+    /// See [`FsApi::get_notifications`]
+    pub async fn get_notifications(&self) -> Result<Option<Vec<Notification>>, Error> {
+        self.with_session(|session_id| self.fsapi().get_notifications(session_id))
+            .await
+    }
+
+    /// Streams the notifications of the session, see [`FsApi::notifications`]
+    ///
+    /// A new session is created when the radio purged ours.
+    /// The stream keeps the session alive until it is dropped.
+    pub fn notifications(
+        &self,
+    ) -> impl Stream<Item = Result<Notification, Error>> + Unpin + Send + 'static {
+        let session = self.clone();
+
+        poll_notifications(
+            move || {
+                let session = session.clone();
+                async move { session.get_notifications().await }
+            },
+            Backoff::forever(),
+        )
+    }
+
+    /// See [`FsApi::get_item_list`]
+    pub async fn get_item_list(&self, node: Node) -> Result<Vec<Item>, Error> {
+        self.with_session(|session_id| self.fsapi().get_item_list(node.clone(), Some(session_id)))
+            .await
+    }
+
+    /// Replaces the session `invalid` by a new one
+    ///
+    /// Does nothing but return the current session if `invalid`
+    /// was already replaced by another task.
+    pub async fn renew(&self, invalid: SessionID) -> Result<SessionID, Error> {
+        let _renew = self.inner.renew.lock().await;
+
+        match self.session_id() {
+            None => return Err(Error::SessionClosed),
+            Some(session_id) if session_id != invalid => return Ok(session_id),
+            Some(_) => (),
+        }
+
+        let session_id = create_session(&self.inner.fsapi, self.inner.backoff).await?;
+        self.inner.session.send_replace(Some(session_id));
+
+        Ok(session_id)
+    }
+
+    /// Deletes the session on the radio
+    ///
+    /// Every clone of this manager stops working afterwards.
+    pub async fn shutdown(&self) -> Result<(), Error> {
+        let _renew = self.inner.renew.lock().await;
+
+        match self.inner.session.send_replace(None) {
+            Some(session_id) => self.inner.fsapi.delete_session(session_id).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        let Some(session_id) = self.session.send_replace(None) else {
+            return;
+        };
+
+        // Without a runtime the session is left for the radio to purge
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let fsapi = self.fsapi.clone();
+            handle.spawn(async move { fsapi.delete_session(session_id).await });
+        }
+    }
+}
+
+/// Creates a session, retrying with `backoff`
+///
+/// A wrong pin is not retried, that won't fix itself.
+async fn create_session(fsapi: &FsApi, backoff: Backoff) -> Result<SessionID, Error> {
+    let mut delays = backoff.delays();
+
+    loop {
+        match fsapi.create_session().await {
+            Ok(session_id) => return Ok(session_id),
+            Err(err @ Error::WrongPin(_)) => return Err(err),
+            Err(err) => match delays.next() {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(err),
+            },
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt::{self, Display};
+
+use quick_xml::{escape::unescape, events::Event};
+
+use crate::{Error, InternalError};
+
+/// A value as sent and returned by the fsapi server
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// `c8_array`: a string
+    Text(String),
+
+    /// `u8`
+    U8(u8),
+
+    /// `u16`
+    U16(u16),
+
+    /// `u32`
+    U32(u32),
+
+    /// `s8`
+    S8(i8),
+
+    /// `s16`
+    S16(i16),
+
+    /// `s32`
+    S32(i32),
+
+    /// `e8`: an enum, the meaning of the number depends on the node
+    E8(u8),
+
+    /// `array`: raw data encoded as a hex string
+    Array(String),
+}
+
+/// The wire type of a [`Value`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    Text,
+    U8,
+    U16,
+    U32,
+    S8,
+    S16,
+    S32,
+    E8,
+    Array,
+}
+
+impl ValueKind {
+    /// Name of the type as used in the xml of the fsapi server
+    pub fn tag(&self) -> &'static str {
+        match self {
+            ValueKind::Text => "c8_array",
+            ValueKind::U8 => "u8",
+            ValueKind::U16 => "u16",
+            ValueKind::U32 => "u32",
+            ValueKind::S8 => "s8",
+            ValueKind::S16 => "s16",
+            ValueKind::S32 => "s32",
+            ValueKind::E8 => "e8",
+            ValueKind::Array => "array",
+        }
+    }
+
+    /// Whether this is one of the integer types
+    pub fn is_integer(&self) -> bool {
+        !matches!(self, ValueKind::Text | ValueKind::Array)
+    }
+
+    fn from_tag(tag: &[u8]) -> Option<Self> {
+        let kind = match tag {
+            b"c8_array" => ValueKind::Text,
+            b"u8" => ValueKind::U8,
+            b"u16" => ValueKind::U16,
+            b"u32" => ValueKind::U32,
+            b"s8" => ValueKind::S8,
+            b"s16" => ValueKind::S16,
+            b"s32" => ValueKind::S32,
+            b"e8" => ValueKind::E8,
+            b"array" => ValueKind::Array,
+            _ => return None,
+        };
+
+        Some(kind)
+    }
+}
+
+impl Display for ValueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tag())
+    }
+}
+
+impl Value {
+    /// The wire type of this value
+    pub fn kind(&self) -> ValueKind {
+        match self {
+            Value::Text(_) => ValueKind::Text,
+            Value::U8(_) => ValueKind::U8,
+            Value::U16(_) => ValueKind::U16,
+            Value::U32(_) => ValueKind::U32,
+            Value::S8(_) => ValueKind::S8,
+            Value::S16(_) => ValueKind::S16,
+            Value::S32(_) => ValueKind::S32,
+            Value::E8(_) => ValueKind::E8,
+            Value::Array(_) => ValueKind::Array,
+        }
+    }
+
+    /// Any of the integer types widened to an i64
+    fn as_integer(&self) -> Option<i64> {
+        match *self {
+            Value::U8(v) | Value::E8(v) => Some(v.into()),
+            Value::U16(v) => Some(v.into()),
+            Value::U32(v) => Some(v.into()),
+            Value::S8(v) => Some(v.into()),
+            Value::S16(v) => Some(v.into()),
+            Value::S32(v) => Some(v.into()),
+            Value::Text(_) | Value::Array(_) => None,
+        }
+    }
+
+    /// Converts an integer value to `T` if it fits
+    fn integer<T: TryFrom<i64>>(&self, expected: ValueKind) -> Result<T, Error> {
+        self.as_integer()
+            .and_then(|v| T::try_from(v).ok())
+            .ok_or(Error::UnexpectedValue {
+                expected,
+                found: self.kind(),
+                context: None,
+            })
+    }
+
+    /// Any integer value that fits in an u8
+    pub fn as_u8(&self) -> Result<u8, Error> {
+        self.integer(ValueKind::U8)
+    }
+
+    /// Any integer value that fits in an u16
+    pub fn as_u16(&self) -> Result<u16, Error> {
+        self.integer(ValueKind::U16)
+    }
+
+    /// Any integer value that fits in an u32
+    pub fn as_u32(&self) -> Result<u32, Error> {
+        self.integer(ValueKind::U32)
+    }
+
+    /// Any integer value that fits in an i8
+    pub fn as_i8(&self) -> Result<i8, Error> {
+        self.integer(ValueKind::S8)
+    }
+
+    /// Any integer value that fits in an i16
+    pub fn as_i16(&self) -> Result<i16, Error> {
+        self.integer(ValueKind::S16)
+    }
+
+    /// Any integer value that fits in an i32
+    pub fn as_i32(&self) -> Result<i32, Error> {
+        self.integer(ValueKind::S32)
+    }
+
+    /// The number of an `e8` enum, plain u8's are accepted as well
+    pub fn as_enum(&self) -> Result<u8, Error> {
+        self.integer(ValueKind::E8)
+    }
+
+    /// An integer flag, anything other than 0 is `true`
+    pub fn as_bool(&self) -> Result<bool, Error> {
+        Ok(self.integer::<i64>(ValueKind::U8)? != 0)
+    }
+
+    /// The string of a `c8_array`
+    pub fn as_text(&self) -> Result<&str, Error> {
+        match self {
+            Value::Text(text) => Ok(text),
+            _ => Err(Error::UnexpectedValue {
+                expected: ValueKind::Text,
+                found: self.kind(),
+                context: None,
+            }),
+        }
+    }
+
+    /// Same as [`Value::as_text`] but takes ownership of the string
+    pub fn into_text(self) -> Result<String, Error> {
+        match self {
+            Value::Text(text) => Ok(text),
+            _ => Err(Error::UnexpectedValue {
+                expected: ValueKind::Text,
+                found: self.kind(),
+                context: None,
+            }),
+        }
+    }
+
+    /// Reads a value like `<u8>1</u8>` (the surrounding `<value>` is not consumed)
+    pub(crate) fn from_reader(
+        reader: &mut quick_xml::Reader<&[u8]>,
+    ) -> Result<Value, InternalError> {
+        let (kind, text) = match reader.read_event()? {
+            Event::Start(ref e) => {
+                let kind = ValueKind::from_tag(e.name().as_ref());
+                (kind, reader.read_text(e.name())?.into_owned())
+            }
+            // Empty strings and arrays are sent as `<c8_array/>`
+            Event::Empty(ref e) => (ValueKind::from_tag(e.name().as_ref()), String::new()),
+            _ => return Err(InternalError::Value(String::from("Incorrect value format"))),
+        };
+
+        let kind = kind.ok_or_else(|| InternalError::Value(String::from("Unknown option")))?;
+
+        Self::parse(kind, &text)
+    }
+
+    /// Parses the text of an xml element of type `kind`
+    fn parse(kind: ValueKind, text: &str) -> Result<Value, InternalError> {
+        let value = match kind {
+            ValueKind::Text => {
+                Value::Text(unescape(text).map_err(quick_xml::Error::from)?.into_owned())
+            }
+            ValueKind::U8 => Value::U8(text.parse()?),
+            ValueKind::U16 => Value::U16(text.parse()?),
+            ValueKind::U32 => Value::U32(text.parse()?),
+            ValueKind::S8 => Value::S8(text.parse()?),
+            ValueKind::S16 => Value::S16(text.parse()?),
+            ValueKind::S32 => Value::S32(text.parse()?),
+            ValueKind::E8 => Value::E8(text.parse()?),
+            ValueKind::Array => Value::Array(text.to_string()),
+        };
+
+        Ok(value)
+    }
+}
+
+/// Formats the value the way the fsapi server expects it in a `SET`
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Text(v) => write!(f, "{v}"),
+            Value::U8(v) => write!(f, "{v}"),
+            Value::U16(v) => write!(f, "{v}"),
+            Value::U32(v) => write!(f, "{v}"),
+            Value::S8(v) => write!(f, "{v}"),
+            Value::S16(v) => write!(f, "{v}"),
+            Value::S32(v) => write!(f, "{v}"),
+            Value::E8(v) => write!(f, "{v}"),
+            Value::Array(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+macro_rules! value_from {
+    ($($from:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$from> for Value {
+                fn from(value: $from) -> Self {
+                    Value::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+value_from! {
+    String => Text,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    i8 => S8,
+    i16 => S16,
+    i32 => S32,
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::U8(value.into())
+    }
+}
@@ -1,6 +1,6 @@
-use std::fmt::Display;
+use fsapi::{FsApi, Node};
 
-use crate::Error;
+use crate::{Error, Values};
 use eq::Eq;
 use volume::Volume;
 
@@ -14,10 +14,21 @@ pub struct Audio {
 }
 
 impl Audio {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let volume = Volume::new(&host, pin).await?;
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
+
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        let mut nodes = Volume::nodes();
+        nodes.extend(Eq::nodes());
+        nodes
+    }
+
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let volume = Volume::from_values(values)?;
 
-        let eq = Eq::new(&host, pin).await?;
+        let eq = Eq::from_values(values)?;
 
         Ok(Self { volume, eq })
     }
@@ -1,17 +1,19 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use tokio::sync::Mutex;
+use tokio::sync::watch;
 
-use fsapi::{FsApi, Node, Value};
+use fsapi::node::{SysAudioEqCustomParam0, SysAudioEqCustomParam1, SysAudioEqLoudness};
+use fsapi::{Error as FsApiError, FsApi, Node, TypedNode, Value, WritableNode};
 
-use crate::{Error, Radio};
+use crate::{take_typed, update, Error, Radio, Values};
 
 #[derive(Debug)]
 pub struct Eq {
-    pub(crate) preset: Mutex<EqPreset>,
+    pub(crate) preset: watch::Sender<EqPreset>,
     pub custom: EqCustom,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum EqPreset {
     Custom = 0,
@@ -25,133 +27,176 @@ pub enum EqPreset {
     News = 8,
 }
 
+/// Typed marker for [`Node::SysAudioEqPreset`] as an [`EqPreset`]
+#[derive(Clone, Copy, Debug)]
+pub struct EqPresetNode;
+
+impl TypedNode for EqPresetNode {
+    const NODE: Node = Node::SysAudioEqPreset;
+
+    type Type = EqPreset;
+
+    fn decode(value: Value) -> Result<EqPreset, FsApiError> {
+        Ok(value.as_u8()?.into())
+    }
+}
+
+impl WritableNode for EqPresetNode {
+    fn encode(preset: EqPreset) -> Value {
+        Value::U8(preset as u8)
+    }
+}
+
 #[derive(Debug)]
 pub struct EqCustom {
-    pub(crate) loudness: Mutex<bool>,
-    pub(crate) bass: Mutex<i32>,
-    pub(crate) trebble: Mutex<i32>,
+    pub(crate) loudness: watch::Sender<bool>,
+    pub(crate) bass: watch::Sender<i32>,
+    pub(crate) trebble: watch::Sender<i32>,
 }
 
 impl Radio {
     pub async fn eq_set(&self, preset: EqPreset) -> Result<(), Error> {
-        self.audio.eq.set(preset, &self.host, self.pin).await
+        self.audio.eq.set(preset, &self.fsapi).await
     }
 
     pub async fn eq_custom_bass_set(&self, bass: i32) -> Result<(), Error> {
-        self.audio
-            .eq
-            .custom
-            .set_bass(bass, &self.host, self.pin)
-            .await
+        self.audio.eq.custom.set_bass(bass, &self.fsapi).await
     }
 
     pub async fn eq_custom_trebble_set(&self, trebble: i32) -> Result<(), Error> {
-        self.audio
-            .eq
-            .custom
-            .set_bass(trebble, &self.host, self.pin)
-            .await
+        self.audio.eq.custom.set_trebble(trebble, &self.fsapi).await
+    }
+
+    pub fn subscribe_eq_preset(&self) -> watch::Receiver<EqPreset> {
+        self.audio.eq.preset.subscribe()
+    }
+
+    pub fn subscribe_eq_loudness(&self) -> watch::Receiver<bool> {
+        self.audio.eq.custom.loudness.subscribe()
+    }
+
+    pub fn subscribe_eq_bass(&self) -> watch::Receiver<i32> {
+        self.audio.eq.custom.bass.subscribe()
+    }
+
+    pub fn subscribe_eq_trebble(&self) -> watch::Receiver<i32> {
+        self.audio.eq.custom.trebble.subscribe()
     }
 }
 
 impl Eq {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let preset: EqPreset = match FsApi::get(Node::SysAudioEqPreset, &host, pin).await? {
-            Value::U8(preset) => preset.into(),
-            _ => unreachable!("SysAudioEqloudness returns a U8"),
-        };
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
 
-        let custom = EqCustom::new(&host, pin).await?;
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        let mut nodes = vec![Node::SysAudioEqPreset];
+        nodes.extend(EqCustom::nodes());
+        nodes
+    }
+
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let preset = take_typed::<EqPresetNode>(values)?;
+
+        let custom = EqCustom::from_values(values)?;
 
         Ok(Self {
-            preset: Mutex::new(preset),
+            preset: watch::channel(preset).0,
             custom,
         })
     }
 
-    pub async fn set<D: Display>(&self, preset: EqPreset, host: D, pin: u32) -> Result<(), Error> {
-        FsApi::set(Node::SysAudioEqPreset, preset as u8, &host, pin).await?;
+    pub fn preset(&self) -> EqPreset {
+        *self.preset.borrow()
+    }
+
+    pub async fn set(&self, preset: EqPreset, fsapi: &FsApi) -> Result<(), Error> {
+        fsapi.set_typed::<EqPresetNode>(preset).await?;
 
-        *self.preset.lock().await = preset;
+        update(&self.preset, preset);
 
         Ok(())
     }
 }
 
 impl EqCustom {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let loudness = match FsApi::get(Node::SysAudioEqLoudness, &host, pin).await? {
-            Value::U8(state) => state == 1,
-            _ => unreachable!("SysAudioEqloudness returns a U8"),
-        };
-
-        let bass = match FsApi::get(Node::SysAudioEqCustomParam0, &host, pin).await? {
-            Value::S16(bass) => bass as i32,
-            _ => unreachable!("SysAudioEqCustomParam0 returns a S16"),
-        };
-
-        let trebble = match FsApi::get(Node::SysAudioEqCustomParam1, &host, pin).await? {
-            Value::S16(bass) => bass as i32,
-            _ => unreachable!("SysAudioEqCustomParam1 returns a S16"),
-        };
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
+
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        vec![
+            Node::SysAudioEqLoudness,
+            Node::SysAudioEqCustomParam0,
+            Node::SysAudioEqCustomParam1,
+        ]
+    }
+
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let loudness = take_typed::<SysAudioEqLoudness>(values)?;
+
+        let bass = take_typed::<SysAudioEqCustomParam0>(values)?.into();
+
+        let trebble = take_typed::<SysAudioEqCustomParam1>(values)?.into();
 
         Ok(Self {
-            loudness: Mutex::new(loudness),
-            bass: Mutex::new(bass),
-            trebble: Mutex::new(trebble),
+            loudness: watch::channel(loudness).0,
+            bass: watch::channel(bass).0,
+            trebble: watch::channel(trebble).0,
         })
     }
 
-    pub async fn set_loudness<D: Display>(
-        &self,
-        loudness: bool,
-        host: D,
-        pin: u32,
-    ) -> Result<(), Error> {
-        let current_loudness = *self.loudness.lock().await;
-        if loudness != current_loudness {
-            FsApi::set(
-                Node::SysAudioEqLoudness,
-                if loudness { 1 } else { 0 },
-                host,
-                pin,
-            )
-            .await?;
-
-            *self.loudness.lock().await = loudness;
+    pub fn loudness(&self) -> bool {
+        *self.loudness.borrow()
+    }
+
+    pub fn bass(&self) -> i32 {
+        *self.bass.borrow()
+    }
+
+    pub fn trebble(&self) -> i32 {
+        *self.trebble.borrow()
+    }
+
+    pub async fn set_loudness(&self, loudness: bool, fsapi: &FsApi) -> Result<(), Error> {
+        if loudness != self.loudness() {
+            fsapi.set_typed::<SysAudioEqLoudness>(loudness).await?;
+
+            update(&self.loudness, loudness);
         }
 
         Ok(())
     }
 
-    pub async fn set_bass<D: Display>(&self, bass: i32, host: D, pin: u32) -> Result<(), Error> {
-        if bass != *self.bass.lock().await {
-            if -7 <= bass && bass <= 7 {
-                FsApi::set(Node::SysAudioEqCustomParam0, bass, host, pin).await?;
+    pub async fn set_bass(&self, bass: i32, fsapi: &FsApi) -> Result<(), Error> {
+        if bass != self.bass() {
+            if (-7..=7).contains(&bass) {
+                fsapi
+                    .set_typed::<SysAudioEqCustomParam0>(bass as i16)
+                    .await?;
             } else {
                 return Err(Error::InvalidValue);
             }
 
-            *self.bass.lock().await = bass;
+            update(&self.bass, bass);
         }
 
         Ok(())
     }
 
-    pub async fn set_trebble<D: Display>(
-        &self,
-        trebble: i32,
-        host: D,
-        pin: u32,
-    ) -> Result<(), Error> {
-        if trebble != *self.trebble.lock().await {
-            if -7 <= trebble && trebble <= 7 {
-                FsApi::set(Node::SysAudioEqCustomParam1, trebble, host, pin).await?;
+    pub async fn set_trebble(&self, trebble: i32, fsapi: &FsApi) -> Result<(), Error> {
+        if trebble != self.trebble() {
+            if (-7..=7).contains(&trebble) {
+                fsapi
+                    .set_typed::<SysAudioEqCustomParam1>(trebble as i16)
+                    .await?;
             } else {
                 return Err(Error::InvalidValue);
             }
 
-            *self.trebble.lock().await = trebble;
+            update(&self.trebble, trebble);
         }
 
         Ok(())
@@ -163,7 +208,7 @@ impl From<u8> for EqPreset {
         if preset <= Self::News as u8 {
             // UNSAFE: We checked above if the `preset` falls within
             // the EqPreset variants
-            unsafe { ::std::mem::transmute(preset) }
+            unsafe { ::std::mem::transmute::<u8, EqPreset>(preset) }
         } else {
             // If the presets is not valid just return normal
             Self::Normal
@@ -1,103 +1,114 @@
-use std::fmt::Display;
-
+use fsapi::node::{SysAudioMute, SysAudioVolume, SysCapsVolumeSteps};
 use fsapi::{FsApi, Node};
-use tokio::sync::Mutex;
+use tokio::sync::watch;
 
-use crate::{Error, Radio};
+use crate::{take_typed, update, Error, Radio, Values};
 
 #[derive(Debug)]
 pub struct Volume {
     pub max_volume: u32,
-    pub volume: Mutex<u32>,
-    pub muted: Mutex<bool>,
+    pub(crate) volume: watch::Sender<u32>,
+    pub(crate) muted: watch::Sender<bool>,
 }
 
 impl Radio {
     pub async fn volume_set(&self, volume: u32) -> Result<(), Error> {
-        self.audio.volume.set(volume, &self.host, self.pin).await
+        self.audio.volume.set(volume, &self.fsapi).await
     }
 
     pub async fn volume_up(&self, change: i32) -> Result<(), Error> {
-        self.audio.volume.up(change, &self.host, self.pin).await
+        self.audio.volume.up(change, &self.fsapi).await
     }
 
     pub async fn volume_mute(&self, mute: bool) -> Result<(), Error> {
-        self.audio.volume.mute(mute, &self.host, self.pin).await
+        self.audio.volume.mute(mute, &self.fsapi).await
     }
 
     pub async fn volume_toggle(&self) -> Result<bool, Error> {
-        self.audio.volume.toggle(&self.host, self.pin).await
+        self.audio.volume.toggle(&self.fsapi).await
+    }
+
+    pub fn subscribe_volume(&self) -> watch::Receiver<u32> {
+        self.audio.volume.volume.subscribe()
+    }
+
+    pub fn subscribe_muted(&self) -> watch::Receiver<bool> {
+        self.audio.volume.muted.subscribe()
     }
 }
 
 impl Volume {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let max_volume: u32 = match FsApi::get(Node::SysCapsVolumeSteps, &host, pin).await? {
-            fsapi::Value::U8(volume_steps) => {
-                (volume_steps as u32).checked_add(1).ok_or(Error::Empty)?
-                //volume_steps.checked_sub(1).ok_or(Error::Empty)? as u32
-            }
-            _ => unreachable!("SysCapsVolumeSteps returns a U8"),
-        };
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
 
-        let volume: u32 = match FsApi::get(Node::SysAudioVolume, &host, pin).await? {
-            fsapi::Value::U8(volume) => volume as u32,
-            _ => unreachable!("SysAudioVolume returns a U8"),
-        };
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        vec![
+            Node::SysCapsVolumeSteps,
+            Node::SysAudioVolume,
+            Node::SysAudioMute,
+        ]
+    }
 
-        let muted = match FsApi::get(Node::SysAudioMute, &host, pin).await? {
-            fsapi::Value::U8(muted) => muted == 1,
-            _ => unreachable!("SysAudioMute returns a U8"),
-        };
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let max_volume = u32::from(take_typed::<SysCapsVolumeSteps>(values)?) + 1;
+
+        let volume = take_typed::<SysAudioVolume>(values)?.into();
+
+        let muted = take_typed::<SysAudioMute>(values)?;
 
         Ok(Self {
             max_volume,
-            volume: Mutex::new(volume),
-            muted: Mutex::new(muted),
+            volume: watch::channel(volume).0,
+            muted: watch::channel(muted).0,
         })
     }
 
-    pub async fn set<D: Display>(&self, volume: u32, host: D, pin: u32) -> Result<(), Error> {
+    pub fn volume(&self) -> u32 {
+        *self.volume.borrow()
+    }
+
+    pub fn muted(&self) -> bool {
+        *self.muted.borrow()
+    }
+
+    pub async fn set(&self, volume: u32, fsapi: &FsApi) -> Result<(), Error> {
         let volume = if volume > self.max_volume {
             self.max_volume
         } else {
             volume
         };
 
-        FsApi::set(Node::SysAudioVolume, volume, host, pin).await?;
+        fsapi.set(Node::SysAudioVolume, volume).await?;
 
-        *self.volume.lock().await = volume;
+        update(&self.volume, volume);
 
         Ok(())
     }
 
-    pub async fn up<D: Display>(&self, change: i32, host: D, pin: u32) -> Result<(), Error> {
-        let new_volume = self
-            .volume
-            .lock()
-            .await
-            .checked_add_signed(change)
-            .unwrap_or(0);
+    pub async fn up(&self, change: i32, fsapi: &FsApi) -> Result<(), Error> {
+        let new_volume = self.volume().checked_add_signed(change).unwrap_or(0);
 
-        self.set(new_volume, host, pin).await
+        self.set(new_volume, fsapi).await
     }
 
-    pub async fn mute<D: Display>(&self, mute: bool, host: D, pin: u32) -> Result<(), Error> {
-        if self.muted.lock().await.eq(&mute) {
-            FsApi::set(Node::SysAudioMute, if mute { 1 } else { 0 }, host, pin).await?;
+    pub async fn mute(&self, mute: bool, fsapi: &FsApi) -> Result<(), Error> {
+        if self.muted() != mute {
+            fsapi.set_typed::<SysAudioMute>(mute).await?;
         };
 
-        *self.muted.lock().await = mute;
+        update(&self.muted, mute);
 
         Ok(())
     }
 
-    pub async fn toggle<D: Display>(&self, host: D, pin: u32) -> Result<bool, Error> {
-        let new = !*self.muted.lock().await;
+    pub async fn toggle(&self, fsapi: &FsApi) -> Result<bool, Error> {
+        let new = !self.muted();
 
-        FsApi::set(Node::SysAudioMute, if new { 1 } else { 0 }, host, pin).await?;
+        fsapi.set_typed::<SysAudioMute>(new).await?;
 
-        *self.muted.lock().await = new;
+        update(&self.muted, new);
 
         Ok(new)
     }
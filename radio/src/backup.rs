@@ -0,0 +1,355 @@
+use std::path::Path;
+
+use fsapi::node::{
+    PlayFrequency, PlayInfoName, PlayServiceIdsDabEnsambleId, PlayServiceIdsDabScids,
+    PlayServiceIdsDabServiceId, PlayServiceIdsEcc, PlayServiceIdsfmRdsPi,
+};
+use fsapi::Node;
+use serde::{Deserialize, Serialize};
+
+use crate::mode::Mode;
+use crate::nav::{path, Preset};
+use crate::{take_typed, Error, Radio};
+
+/// Modes with presets, backed up by [`Radio::backup_presets`]
+pub const PRESET_MODES: [Mode; 3] = [Mode::Internet, Mode::Dab, Mode::Fm];
+
+/// The presets of a radio, to restore them after a factory reset
+///
+/// Written as TOML or JSON, see [`BackupFormat`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PresetBackup {
+    pub presets: Vec<BackupPreset>,
+}
+
+/// A preset and how to find its station again
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupPreset {
+    pub slot: u32,
+    pub name: String,
+    pub mode: Mode,
+
+    #[serde(default)]
+    pub station: Station,
+}
+
+/// Identifiers of a station, only the ones the mode has are set
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Station {
+    /// Fm frequency, as reported by the radio
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dab_service_id: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dab_ensemble_id: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dab_scids: Option<u8>,
+
+    /// Extended country code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ecc: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fm_rds_pi: Option<u16>,
+}
+
+/// What [`Radio::backup_presets`] backed up and what went wrong
+#[derive(Debug)]
+pub struct BackupReport {
+    pub backup: PresetBackup,
+
+    /// Modes that could not be backed up, or only partly
+    pub failures: Vec<BackupFailure>,
+
+    /// Why the radio could not be switched back to the mode it was in
+    pub switch_back: Option<Error>,
+}
+
+/// What went wrong during [`Radio::restore_presets`]
+#[derive(Debug)]
+pub struct RestoreReport {
+    /// Presets that could not be restored, the others were
+    pub failures: Vec<RestoreFailure>,
+
+    /// Why the radio could not be switched back to the mode it was in
+    pub switch_back: Option<Error>,
+}
+
+/// A mode [`Radio::backup_presets`] could not back up, or only partly
+#[derive(Debug)]
+pub struct BackupFailure {
+    pub mode: Mode,
+    pub error: Error,
+}
+
+/// A preset [`Radio::restore_presets`] could not restore
+#[derive(Debug)]
+pub struct RestoreFailure {
+    pub preset: BackupPreset,
+    pub error: Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupFormat {
+    Toml,
+    Json,
+}
+
+impl BackupFormat {
+    /// Guesses the format by the extension, TOML unless it is `.json`
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        match path.as_ref().extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => BackupFormat::Json,
+            _ => BackupFormat::Toml,
+        }
+    }
+}
+
+impl PresetBackup {
+    pub fn to_string(&self, format: BackupFormat) -> Result<String, Error> {
+        let text = match format {
+            BackupFormat::Toml => toml::to_string_pretty(self).map_err(|err| err.to_string()),
+            BackupFormat::Json => serde_json::to_string_pretty(self).map_err(|err| err.to_string()),
+        };
+
+        text.map_err(Error::InvalidBackup)
+    }
+
+    pub fn from_str(text: &str, format: BackupFormat) -> Result<Self, Error> {
+        let backup = match format {
+            BackupFormat::Toml => toml::from_str(text).map_err(|err| err.to_string()),
+            BackupFormat::Json => serde_json::from_str(text).map_err(|err| err.to_string()),
+        };
+
+        backup.map_err(Error::InvalidBackup)
+    }
+
+    /// Writes the backup in the format of the extension of `path`
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let text = self.to_string(BackupFormat::from_path(&path))?;
+
+        Ok(std::fs::write(path, text)?)
+    }
+
+    /// Reads a backup in the format of the extension of `path`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let text = std::fs::read_to_string(&path)?;
+
+        Self::from_str(&text, BackupFormat::from_path(&path))
+    }
+}
+
+impl Radio {
+    /// Backs up the presets of every mode in [`PRESET_MODES`]
+    ///
+    /// To read the identifiers of the stations every preset is played,
+    /// a station that does not play is kept without identifiers.
+    /// Modes that could not be backed up are reported next to the backup.
+    /// Afterwards the radio is switched back to the mode it was in,
+    /// see [`BackupReport::switch_back`].
+    pub async fn backup_presets(&self) -> BackupReport {
+        let start_mode = self.mode();
+        let mut backup = PresetBackup::default();
+        let mut failures = Vec::new();
+
+        for mode in PRESET_MODES {
+            if let Err(error) = self.backup_mode(mode, &mut backup).await {
+                failures.push(BackupFailure { mode, error });
+            }
+        }
+
+        BackupReport {
+            backup,
+            failures,
+            switch_back: self.backup_switch_back(start_mode).await,
+        }
+    }
+
+    /// Stores every preset of `backup` again
+    ///
+    /// Every station is searched by its identifiers or name and played,
+    /// then stored in its slot. Presets that could not be found are reported,
+    /// the other presets are restored anyway.
+    /// Afterwards the radio is switched back to the mode it was in,
+    /// see [`RestoreReport::switch_back`].
+    pub async fn restore_presets(&self, backup: &PresetBackup) -> RestoreReport {
+        let start_mode = self.mode();
+        let mut failures = Vec::new();
+
+        for preset in &backup.presets {
+            if let Err(error) = self.restore_preset(preset).await {
+                failures.push(RestoreFailure {
+                    preset: preset.clone(),
+                    error,
+                });
+            }
+        }
+
+        RestoreReport {
+            failures,
+            switch_back: self.backup_switch_back(start_mode).await,
+        }
+    }
+
+    /// Switches back to `mode` after a backup or restore
+    ///
+    /// Modes this crate does not know, like bluetooth, can't be set
+    /// and are skipped, the radio stays in the last preset mode then.
+    async fn backup_switch_back(&self, mode: Mode) -> Option<Error> {
+        if mode == Mode::FallBack {
+            return None;
+        }
+
+        self.mode_set(mode).await.err()
+    }
+
+    /// Switches to `mode` and waits until its menu is ready
+    async fn backup_switch_mode(&self, mode: Mode) -> Result<(), Error> {
+        if self.mode() == mode && self.nav.state() {
+            return Ok(());
+        }
+
+        self.mode_set(mode).await?;
+        self.nav_enable().await?;
+        self.nav_wait_ready().await
+    }
+
+    async fn backup_mode(&self, mode: Mode, backup: &mut PresetBackup) -> Result<(), Error> {
+        self.backup_switch_mode(mode).await?;
+
+        let presets = self.presets_refresh().await?;
+        for preset in presets.into_iter().filter(|preset| !preset.is_empty()) {
+            backup.presets.push(self.backup_preset(preset).await?);
+        }
+
+        Ok(())
+    }
+
+    async fn backup_preset(&self, preset: Preset) -> Result<BackupPreset, Error> {
+        let station = match self.preset_play(preset.slot).await {
+            Ok(()) => self.station().await?,
+            // Keep the name, the station may be back on air by the restore
+            Err(Error::Timeout) => Station::default(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(BackupPreset {
+            slot: preset.slot,
+            name: preset.name,
+            mode: preset.kind,
+            station,
+        })
+    }
+
+    async fn restore_preset(&self, preset: &BackupPreset) -> Result<(), Error> {
+        self.backup_switch_mode(preset.mode).await?;
+        let playing = self.fsapi.get_typed::<PlayInfoName>().await.ok();
+
+        match (preset.mode, preset.station.frequency) {
+            (Mode::Fm, Some(frequency)) => {
+                self.fsapi.set_typed::<PlayFrequency>(frequency).await?;
+            }
+            // The stations are the root of the dab menu
+            (Mode::Dab, _) => {
+                self.nav_navigate_path(&[&preset.name]).await?;
+            }
+            _ => {
+                let results = self.nav_search(&preset.name).await?;
+                let station = path::find(&results, &preset.name, None).ok_or_else(|| {
+                    Error::PathNotFound {
+                        segment: preset.name.clone(),
+                        folder: String::from("search results"),
+                    }
+                })?;
+                self.nav_open(station).await?;
+            }
+        }
+
+        self.player_wait_tuned(playing).await?;
+        self.preset_store(preset.slot).await?;
+
+        Ok(())
+    }
+
+    /// The identifiers of the station that is playing
+    async fn station(&self) -> Result<Station, Error> {
+        let mut values = self
+            .fsapi
+            .get_multiple([
+                Node::PlayFrequency,
+                Node::PlayServiceIdsDabServiceId,
+                Node::PlayServiceIdsDabEnsambleId,
+                Node::PlayServiceIdsDabScids,
+                Node::PlayServiceIdsEcc,
+                Node::PlayServiceIdsfmRdsPi,
+            ])
+            .await?;
+
+        // Nodes of other modes are blocked
+        Ok(Station {
+            frequency: take_typed::<PlayFrequency>(&mut values).ok(),
+            dab_service_id: take_typed::<PlayServiceIdsDabServiceId>(&mut values).ok(),
+            dab_ensemble_id: take_typed::<PlayServiceIdsDabEnsambleId>(&mut values).ok(),
+            dab_scids: take_typed::<PlayServiceIdsDabScids>(&mut values).ok(),
+            ecc: take_typed::<PlayServiceIdsEcc>(&mut values).ok(),
+            fm_rds_pi: take_typed::<PlayServiceIdsfmRdsPi>(&mut values).ok(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backup() -> PresetBackup {
+        PresetBackup {
+            presets: vec![
+                BackupPreset {
+                    slot: 0,
+                    name: String::from("Jazz FM"),
+                    mode: Mode::Fm,
+                    station: Station {
+                        frequency: Some(102200),
+                        fm_rds_pi: Some(0xc0de),
+                        ..Station::default()
+                    },
+                },
+                BackupPreset {
+                    slot: 3,
+                    name: String::from("BBC Radio 4"),
+                    mode: Mode::Internet,
+                    station: Station::default(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trips_as_toml_and_json() {
+        for format in [BackupFormat::Toml, BackupFormat::Json] {
+            let text = backup().to_string(format).unwrap();
+
+            assert_eq!(PresetBackup::from_str(&text, format).unwrap(), backup());
+        }
+    }
+
+    #[test]
+    fn missing_station_is_empty() {
+        let text = "[[presets]]\nslot = 1\nname = \"News\"\nmode = \"Dab\"\n";
+        let backup = PresetBackup::from_str(text, BackupFormat::Toml).unwrap();
+
+        assert_eq!(backup.presets[0].station, Station::default());
+    }
+
+    #[test]
+    fn format_follows_the_extension() {
+        assert_eq!(BackupFormat::from_path("presets.json"), BackupFormat::Json);
+        assert_eq!(BackupFormat::from_path("PRESETS.JSON"), BackupFormat::Json);
+        assert_eq!(BackupFormat::from_path("presets.toml"), BackupFormat::Toml);
+        assert_eq!(BackupFormat::from_path("presets"), BackupFormat::Toml);
+    }
+}
@@ -2,6 +2,8 @@ use fsapi::Error as FsApiError;
 use std::fmt;
 use tokio::task::JoinError;
 
+use crate::mode::Mode;
+
 /// The default error type for this crate
 #[derive(Debug)]
 pub enum Error {
@@ -22,6 +24,26 @@ pub enum Error {
 
     /// Could not get a lock
     Lock,
+
+    /// The current mode does not support the action,
+    /// see [`PlayerCapabilities`](crate::player::PlayerCapabilities)
+    Unsupported { action: &'static str, mode: Mode },
+
+    /// No entry in `folder` matches `segment`,
+    /// see [`Radio::nav_navigate_path`](crate::Radio::nav_navigate_path)
+    PathNotFound { segment: String, folder: String },
+
+    /// The menu did not go up, it is still `depth` deep
+    NavStuck { depth: u8 },
+
+    /// A backup file could not be read or written
+    Io(std::io::Error),
+
+    /// A backup is not valid TOML or JSON
+    InvalidBackup(String),
+
+    /// A request to the radio failed, with the node and operation in its context
+    FsApi(FsApiError),
 }
 
 impl fmt::Display for Error {
@@ -33,24 +55,46 @@ impl fmt::Display for Error {
             Error::InvalidValue => write!(f, "Invalid value"),
             Error::Lock => write!(f, "Could not get a lock"),
             Error::Empty => write!(f, ""),
+            Error::PathNotFound { segment, folder } if folder.is_empty() => {
+                write!(f, "{segment} not found in the menu")
+            }
+            Error::PathNotFound { segment, folder } => write!(f, "{segment} not found in {folder}"),
+            Error::NavStuck { depth } => write!(f, "The menu is stuck {depth} deep"),
+            Error::Io(err) => write!(f, "{err}"),
+            Error::InvalidBackup(msg) => write!(f, "Invalid backup: {msg}"),
+            Error::Unsupported { action, mode } => write!(f, "{action} is not supported in {mode}"),
+            Error::FsApi(err) => write!(f, "{err}"),
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::FsApi(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl From<FsApiError> for Error {
     fn from(err: FsApiError) -> Self {
-        dbg!(&err);
         match err {
-            FsApiError::WrongPin => Error::Auth,
-            FsApiError::Timeout => Error::Timeout,
-            FsApiError::Fail => Error::InvalidValue,
-            _ => Error::Oops,
+            FsApiError::WrongPin(_) => Error::Auth,
+            err if err.is_timeout() => Error::Timeout,
+            FsApiError::Fail(_) => Error::InvalidValue,
+            err => Error::FsApi(err),
         }
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
 impl From<JoinError> for Error {
     fn from(_: JoinError) -> Self {
         Self::Oops
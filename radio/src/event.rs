@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use fsapi::node::{
+    NavStatus, PlayInfoAlbum, PlayInfoArtist, PlayInfoDuration, PlayInfoGraphicUri, PlayInfoName,
+    PlayInfoText, PlayPosition, PlayRate, PlayRepeat, PlayShuffle, PlayShuffleStatus,
+    PlaySignalStrength, SysAudioEqCustomParam0, SysAudioEqCustomParam1, SysAudioEqLoudness,
+    SysAudioMute, SysAudioVolume, SysPower, SysSleep,
+};
+use fsapi::{Node, Notification, TypedNode};
+
+use crate::audio::eq::{EqPreset, EqPresetNode};
+use crate::mode::{Mode, ModeNode};
+use crate::player::Status;
+
+/// A change on the radio, decoded from a [`Notification`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RadioEvent {
+    VolumeChanged(u32),
+    Muted(bool),
+    PowerChanged(bool),
+    ModeChanged(Mode),
+    EqPresetChanged(EqPreset),
+    LoudnessChanged(bool),
+    BassChanged(i32),
+    TrebbleChanged(i32),
+
+    /// Only the fields that changed are set,
+    /// the radio sends a notification per field
+    TrackChanged {
+        name: Option<String>,
+        artist: Option<String>,
+        album: Option<String>,
+    },
+
+    /// Second line of the display
+    TextChanged(String),
+    DurationChanged(Duration),
+    GraphicUriChanged(String),
+    PositionChanged(Duration),
+    PlayStatusChanged(Status),
+    SignalStrengthChanged(u8),
+    ShuffleChanged(bool),
+    RepeatChanged(bool),
+
+    /// Playback speed, negative while rewinding
+    RateChanged(i8),
+
+    /// Whether the menu is ready to be browsed
+    NavReady(bool),
+
+    /// Time until the radio goes to sleep, zero if disabled
+    SleepChanged(Duration),
+
+    /// A notification that is not (yet) decoded, or that had an unexpected value
+    Unknown(Notification),
+}
+
+impl RadioEvent {
+    fn track(name: Option<String>, artist: Option<String>, album: Option<String>) -> Self {
+        RadioEvent::TrackChanged {
+            name,
+            artist,
+            album,
+        }
+    }
+
+    /// Decodes the notification, `None` for unknown nodes or unexpected values
+    fn decode(notification: &Notification) -> Option<Self> {
+        use RadioEvent::*;
+
+        let value = notification.value.clone();
+        let event = match notification.node {
+            Node::SysAudioVolume => VolumeChanged(SysAudioVolume::decode(value).ok()?.into()),
+            Node::SysAudioMute => Muted(SysAudioMute::decode(value).ok()?),
+            Node::SysPower => PowerChanged(SysPower::decode(value).ok()?),
+            Node::SysMode => ModeChanged(ModeNode::decode(value).ok()?),
+            Node::SysAudioEqPreset => EqPresetChanged(EqPresetNode::decode(value).ok()?),
+            Node::SysAudioEqLoudness => LoudnessChanged(SysAudioEqLoudness::decode(value).ok()?),
+            Node::SysAudioEqCustomParam0 => {
+                BassChanged(SysAudioEqCustomParam0::decode(value).ok()?.into())
+            }
+            Node::SysAudioEqCustomParam1 => {
+                TrebbleChanged(SysAudioEqCustomParam1::decode(value).ok()?.into())
+            }
+            Node::PlayInfoName => Self::track(Some(PlayInfoName::decode(value).ok()?), None, None),
+            Node::PlayInfoArtist => {
+                Self::track(None, Some(PlayInfoArtist::decode(value).ok()?), None)
+            }
+            Node::PlayInfoAlbum => {
+                Self::track(None, None, Some(PlayInfoAlbum::decode(value).ok()?))
+            }
+            Node::PlayInfoText => TextChanged(PlayInfoText::decode(value).ok()?),
+            Node::PlayInfoDuration => DurationChanged(PlayInfoDuration::decode(value).ok()?),
+            Node::PlayInfoGraphicUri => GraphicUriChanged(PlayInfoGraphicUri::decode(value).ok()?),
+            Node::PlayPosition => PositionChanged(PlayPosition::decode(value).ok()?),
+            Node::PlayStatus => PlayStatusChanged(Status::from_value(value).ok()?),
+            Node::PlaySignalStrength => {
+                SignalStrengthChanged(PlaySignalStrength::decode(value).ok()?)
+            }
+            Node::PlayShuffle => ShuffleChanged(PlayShuffle::decode(value).ok()?),
+            Node::PlayShuffleStatus => ShuffleChanged(PlayShuffleStatus::decode(value).ok()?),
+            Node::PlayRepeat => RepeatChanged(PlayRepeat::decode(value).ok()?),
+            Node::PlayRate => RateChanged(PlayRate::decode(value).ok()?),
+            Node::NavStatus => NavReady(NavStatus::decode(value).ok()? == 1),
+            Node::SysSleep => SleepChanged(SysSleep::decode(value).ok()?),
+            _ => return None,
+        };
+
+        Some(event)
+    }
+}
+
+impl From<Notification> for RadioEvent {
+    fn from(notification: Notification) -> Self {
+        Self::decode(&notification).unwrap_or(RadioEvent::Unknown(notification))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fsapi::Value;
+
+    use super::*;
+
+    fn event(node: Node, value: Value) -> RadioEvent {
+        RadioEvent::from(Notification { node, value })
+    }
+
+    #[test]
+    fn known_nodes_are_decoded() {
+        assert_eq!(
+            event(Node::SysAudioVolume, Value::U8(7)),
+            RadioEvent::VolumeChanged(7)
+        );
+        assert_eq!(
+            event(Node::SysMode, Value::U32(4)),
+            RadioEvent::ModeChanged(Mode::Dab)
+        );
+        assert_eq!(
+            event(Node::PlayInfoName, Value::Text(String::from("News"))),
+            RadioEvent::track(Some(String::from("News")), None, None)
+        );
+        assert_eq!(
+            event(Node::PlayInfoDuration, Value::U32(1500)),
+            RadioEvent::DurationChanged(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            event(Node::NavStatus, Value::U8(1)),
+            RadioEvent::NavReady(true)
+        );
+    }
+
+    #[test]
+    fn unknown_nodes_pass_through() {
+        let notification = Notification {
+            node: Node::from("netremote.multiroom.device.serverstatus"),
+            value: Value::U8(1),
+        };
+
+        assert_eq!(
+            RadioEvent::from(notification.clone()),
+            RadioEvent::Unknown(notification)
+        );
+    }
+
+    #[test]
+    fn unexpected_values_pass_through() {
+        let notification = Notification {
+            node: Node::SysAudioVolume,
+            value: Value::Text(String::from("loud")),
+        };
+
+        assert_eq!(
+            RadioEvent::from(notification.clone()),
+            RadioEvent::Unknown(notification)
+        );
+    }
+}
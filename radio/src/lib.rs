@@ -1,166 +1,253 @@
-#![feature(mixed_integer_ops)]
-use fsapi::{FsApi, Node, Notification, SessionID, Value};
-use tokio::sync::Mutex;
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock};
+use std::time::Duration;
+
+use fsapi::{Error as FsApiError, FsApi, Node, Notification, SessionManager, TypedNode, Value};
+use futures_util::{Stream, StreamExt};
+use tokio::sync::watch;
 
-use audio::eq::EqPreset;
 use audio::Audio;
 pub use error::Error;
+pub use event::RadioEvent;
 use mode::Mode;
 use nav::Nav;
 use player::Player;
 use power::Power;
 use sleep::Sleep;
+pub use state::RadioState;
+pub use sync::SyncHandle;
 
 pub mod audio;
+pub mod backup;
 pub mod error;
+pub mod event;
 pub mod mode;
 pub mod nav;
 pub mod player;
 pub mod power;
 pub mod sleep;
+pub mod state;
+pub mod sync;
 
 #[derive(Debug)]
 pub struct Radio {
-    pub(crate) host: String,
-    pub(crate) pin: u32,
-    pub(crate) session_id: SessionID,
+    pub(crate) fsapi: FsApi,
+    pub(crate) session: SessionManager,
     pub audio: Audio,
     pub player: Player,
     pub nav: Nav,
     pub power: Power,
     pub sleep: Sleep,
-    pub mode: Mutex<Mode>,
+    pub(crate) mode: watch::Sender<Mode>,
+
+    /// Held for writing while an event is applied,
+    /// so a [`RadioState`] never sees half of an event
+    pub(crate) update_lock: RwLock<()>,
+}
+
+/// Node values returned by a batched [`FsApi::get_multiple`]
+pub(crate) type Values = HashMap<Node, Result<Value, FsApiError>>;
+
+/// Takes the value of `node` out of a batched response
+pub(crate) fn take_value(values: &mut Values, node: Node) -> Result<Value, Error> {
+    Ok(values.remove(&node).ok_or(Error::Oops)??)
+}
+
+/// Takes the value of a typed node out of a batched response
+pub(crate) fn take_typed<N: TypedNode>(values: &mut Values) -> Result<N::Type, Error> {
+    Ok(N::decode(take_value(values, N::NODE)?)?)
+}
+
+/// Replaces a watched value, subscribers are only notified when it changed
+pub(crate) fn update<T: PartialEq>(state: &watch::Sender<T>, value: T) -> bool {
+    update_with(state, |current| current, value)
+}
+
+/// Replaces one field of a watched value, see [`update`]
+pub(crate) fn update_with<T, U: PartialEq>(
+    state: &watch::Sender<T>,
+    field: impl FnOnce(&mut T) -> &mut U,
+    value: U,
+) -> bool {
+    state.send_if_modified(|current| {
+        let current = field(current);
+        if *current == value {
+            return false;
+        }
+
+        *current = value;
+        true
+    })
 }
 
 impl Radio {
     pub async fn new<T: ToString>(host: T, pin: u32) -> Result<Self, Box<dyn std::error::Error>> {
-        let host = host.to_string();
+        let fsapi = FsApi::new(host.to_string(), pin)?;
+
+        Self::with_client(fsapi).await
+    }
+
+    /// Connect to a radio using an already configured [`FsApi`] client
+    pub async fn with_client(fsapi: FsApi) -> Result<Self, Box<dyn std::error::Error>> {
+        let session = SessionManager::new(fsapi.clone()).await?;
 
-        let session_id = FsApi::create_session(&host, pin).await?;
+        let nodes = [
+            Audio::nodes(),
+            Player::nodes(),
+            Power::nodes(),
+            Sleep::nodes(),
+            Mode::nodes(),
+        ]
+        .concat();
+        let mut values = fsapi.get_multiple(nodes).await?;
 
-        let audio = Audio::new(&host, pin).await?;
+        let audio = Audio::from_values(&mut values)?;
 
-        let player = Player::new(&host, pin).await?;
+        let player = Player::from_values(&mut values)?;
 
-        let nav = Nav::new(&host, pin).await?;
+        let power = Power::from_values(&mut values)?;
 
-        let power = Power::new(&host, pin).await?;
+        let sleep = Sleep::from_values(&mut values)?;
 
-        let sleep = Sleep::new(&host, pin).await?;
+        let mode = Mode::from_values(&mut values)?;
 
-        let mode = Mode::new(&host, pin).await?;
+        let nav = Nav::new(&fsapi, mode).await?;
 
         Ok(Self {
-            host,
-            pin,
-            session_id,
+            fsapi,
+            session,
             audio,
             player,
             nav,
             power,
             sleep,
-            mode: Mutex::new(mode),
+            mode: watch::channel(mode).0,
+            update_lock: RwLock::new(()),
         })
     }
 
     pub async fn get_notifications(&self) -> Result<Option<Vec<Notification>>, Error> {
-        //let host = radio.host.clone();
-        //let pin = radio.pin.clone();
-        //let session_id = radio.session_id;
-
-        let notifications = FsApi::get_notifications(self.session_id, &self.host, self.pin).await?;
+        let notifications = self.session.get_notifications().await?;
 
         Ok(notifications)
     }
 
+    /// Streams every change on the radio
+    ///
+    /// Keeps listening through `FS_TIMEOUT`s, network errors and purged
+    /// sessions, see [`SessionManager::notifications`]. Drop the stream to stop.
+    ///
+    /// The state of the radio is not updated, pass the events to [`Radio::handle_event`].
+    pub fn events(&self) -> impl Stream<Item = Result<RadioEvent, Error>> + Unpin + Send + 'static {
+        self.session
+            .notifications()
+            .map(|notification| Ok(RadioEvent::from(notification?)))
+    }
+
+    /// Deletes the session on the radio, for good
+    ///
+    /// Notifications, menus and presets stop working afterwards.
+    pub async fn shutdown(&self) -> Result<(), Error> {
+        Ok(self.session.shutdown().await?)
+    }
+
+    /// Updates the state of the radio with a notification
     pub async fn handle_notification(&self, notification: Notification) -> Result<(), Error> {
-        use Node::*;
+        self.handle_event(&RadioEvent::from(notification)).await
+    }
 
-        match notification.node {
-            SysAudioVolume => {
-                if let Value::U8(volume) = notification.value {
-                    *self.audio.volume.volume.lock().await = volume as u32;
-                }
-            }
-            SysAudioMute => {
-                if let Value::U8(mute) = notification.value {
-                    *self.audio.volume.muted.lock().await = mute == 1;
-                }
-            }
-            SysPower => {
-                if let Value::U8(state) = notification.value {
-                    *self.power.state.lock().await = state == 1;
-                }
-            }
-            SysAudioEqPreset => {
-                if let Value::U8(preset) = notification.value {
-                    *self.audio.eq.preset.lock().await = EqPreset::from(preset);
-                }
-            }
-            SysAudioEqLoudness => {
-                if let Value::U8(loudness) = notification.value {
-                    *self.audio.eq.custom.loudness.lock().await = loudness == 1;
-                }
-            }
-            SysAudioEqCustomParam0 => {
-                if let Value::S16(bass) = notification.value {
-                    *self.audio.eq.custom.bass.lock().await = bass.into();
-                }
-            }
-            SysAudioEqCustomParam1 => {
-                if let Value::S16(trebble) = notification.value {
-                    *self.audio.eq.custom.trebble.lock().await = trebble.into();
-                }
-            }
-            PlayInfoName => {
-                if let Value::Text(name) = notification.value {
-                    *self.player.info.name.lock().await = name;
-                }
-            }
-            PlayInfoText => {
-                if let Value::Text(text) = notification.value {
-                    *self.player.info.text.lock().await = text;
-                }
-            }
-            PlayInfoAlbum => {
-                if let Value::Text(album) = notification.value {
-                    *self.player.info.album.lock().await = album
-                }
-            }
-            PlayInfoArtist => {
-                if let Value::Text(artist) = notification.value {
-                    *self.player.info.artist.lock().await = artist;
-                }
+    /// Updates the state of the radio with an event from [`Radio::events`]
+    ///
+    /// Subscribers of the changed state are notified,
+    /// events that don't change anything are ignored.
+    pub async fn handle_event(&self, event: &RadioEvent) -> Result<(), Error> {
+        let changed = self.apply(event);
+        if !changed {
+            return Ok(());
+        }
+
+        match event {
+            // Every mode has its own capabilities
+            RadioEvent::ModeChanged(_) => {
+                self.player_refresh_capabilities().await;
+                self.player_refresh_graphic_uri().await;
             }
-            PlayInfoDuration => {
-                if let Value::U32(duration) = notification.value {
-                    *self.player.info.duration.lock().await =
-                        std::time::Duration::from_millis(duration.into());
-                }
+            RadioEvent::TrackChanged { name: Some(_), .. } => {
+                self.player_refresh_graphic_uri().await;
             }
-            PlayInfoGraphicUri => {
-                if let Value::Text(graphic_uri) = notification.value {
-                    *self.player.info.graphic_uri.lock().await = graphic_uri;
-                }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    /// Applies `event` to the state, returns whether anything changed
+    fn apply(&self, event: &RadioEvent) -> bool {
+        use RadioEvent::*;
+
+        let _update = self
+            .update_lock
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let now_playing = &self.player.info.now_playing;
+
+        match event.clone() {
+            VolumeChanged(volume) => update(&self.audio.volume.volume, volume),
+            Muted(muted) => update(&self.audio.volume.muted, muted),
+            PowerChanged(state) => update(&self.power.state, state),
+            ModeChanged(mode) => {
+                // The radio resets the menu on every mode change
+                update(&self.nav.state, false);
+                update(&self.mode, mode)
             }
-            SysMode => {
-                if let Value::U32(mode) = notification.value {
-                    *self.mode.lock().await = mode.into();
+            EqPresetChanged(preset) => update(&self.audio.eq.preset, preset),
+            LoudnessChanged(loudness) => update(&self.audio.eq.custom.loudness, loudness),
+            BassChanged(bass) => update(&self.audio.eq.custom.bass, bass),
+            TrebbleChanged(trebble) => update(&self.audio.eq.custom.trebble, trebble),
+            TrackChanged {
+                name,
+                artist,
+                album,
+            } => {
+                let changed = now_playing.send_if_modified(|now_playing| {
+                    let mut changed = false;
+                    for (field, value) in [
+                        (&mut now_playing.name, name),
+                        (&mut now_playing.artist, artist),
+                        (&mut now_playing.album, album),
+                    ] {
+                        if let Some(value) = value.filter(|value| value != field) {
+                            *field = value;
+                            changed = true;
+                        }
+                    }
+                    changed
+                });
+
+                // A new track starts at the beginning, until the next poll
+                if changed {
+                    self.player.update_position(Duration::ZERO);
                 }
+                changed
             }
-            PlayStatus => {
-                if let Value::U8(status) = notification.value {
-                    *self.player.status.lock().await = status.into();
+            TextChanged(text) => update_with(now_playing, |info| &mut info.text, text),
+            DurationChanged(duration) => {
+                let changed = update_with(now_playing, |info| &mut info.duration, duration);
+                if changed {
+                    self.player.update_position(Duration::ZERO);
                 }
+                changed
             }
-            PlayServiceIdsEcc => (),
-            SysState => (),
-            SysClockLocalTime => (),
-            SysClockLocalDate => (),
-
-            node => panic!("Update node: {:?}", node),
+            GraphicUriChanged(uri) => update_with(now_playing, |info| &mut info.graphic_uri, uri),
+            PlayStatusChanged(status) => self.player.update_status(status),
+            PositionChanged(position) => self.player.update_position(position),
+            SignalStrengthChanged(strength) => update(&self.player.signal_strength, strength),
+            ShuffleChanged(shuffle) => update(&self.player.shuffle, shuffle),
+            RepeatChanged(repeat) => update(&self.player.repeat, repeat),
+            RateChanged(rate) => self.player.update_rate(rate),
+            NavReady(ready) => update(&self.nav.ready, ready),
+            SleepChanged(sleep_in) => update(&self.sleep.sleep_in, sleep_in),
+            Unknown(_) => false,
         }
-
-        Ok(())
     }
 }
@@ -1,10 +1,13 @@
 use std::fmt::Display;
 
-use fsapi::{FsApi, Node, Value};
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
 
-use crate::{Error, Radio};
+use fsapi::{Error as FsApiError, FsApi, Node, TypedNode, Value, WritableNode};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use crate::{take_typed, update, Error, Radio, RadioEvent, Values};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u32)]
 pub enum Mode {
     Internet = 0,
@@ -19,31 +22,73 @@ pub enum Mode {
     FallBack = 7,
 }
 
+/// Typed marker for [`Node::SysMode`] as a [`Mode`]
+#[derive(Clone, Copy, Debug)]
+pub struct ModeNode;
+
+impl TypedNode for ModeNode {
+    const NODE: Node = Node::SysMode;
+
+    type Type = Mode;
+
+    fn decode(value: Value) -> Result<Mode, FsApiError> {
+        Ok(value.as_u32()?.into())
+    }
+}
+
+impl WritableNode for ModeNode {
+    fn encode(mode: Mode) -> Value {
+        Value::U32(mode as u32)
+    }
+}
+
 impl Radio {
+    /// Switches to `mode`, the radio turns off the navigation with it
     pub async fn mode_set(&self, mode: Mode) -> Result<(), Error> {
-        Mode::set(mode, &self.host, self.pin).await?;
+        Mode::set(mode, &self.fsapi).await?;
 
-        //self.mode = mode;
-        Ok(())
+        // Enable the menu again on next use, also if the mode stayed the same
+        update(&self.nav.state, false);
+        self.handle_event(&RadioEvent::ModeChanged(mode)).await
+    }
+
+    /// The current mode, as last reported by the radio
+    pub fn mode(&self) -> Mode {
+        *self.mode.borrow()
+    }
+
+    pub fn subscribe_mode(&self) -> watch::Receiver<Mode> {
+        self.mode.subscribe()
     }
 }
 
 impl Mode {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let mode = match FsApi::get(Node::SysMode, &host, pin).await? {
-            Value::U32(mode) => mode.into(),
-            _ => unreachable!("SysMode returns a U32"),
-        };
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
+
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        vec![Node::SysMode]
+    }
+
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let mode = take_typed::<ModeNode>(values)?;
 
         Ok(mode)
     }
 
-    pub async fn set<D: Display>(mode: Mode, host: D, pin: u32) -> Result<(), Error> {
+    /// Whether the mode plays tracks with a position and duration
+    pub fn has_position(&self) -> bool {
+        matches!(self, Mode::MusicPlayer | Mode::Dmr | Mode::Spotify)
+    }
+
+    pub async fn set(mode: Mode, fsapi: &FsApi) -> Result<(), Error> {
         if mode == Mode::FallBack {
             return Err(Error::InvalidValue);
         }
 
-        FsApi::set(Node::SysMode, mode as u32, host, pin).await?;
+        fsapi.set_typed::<ModeNode>(mode).await?;
 
         Ok(())
     }
@@ -54,7 +99,7 @@ impl From<u32> for Mode {
         if mode <= Self::AuxIn as u32 {
             // UNSAFE: We checked above if the `mode` falls within
             // the Mode variants
-            unsafe { ::std::mem::transmute(mode) }
+            unsafe { ::std::mem::transmute::<u32, Mode>(mode) }
         } else {
             // If the presets is not valid just return normal
             Self::FallBack
@@ -1,39 +1,62 @@
-use std::fmt::Display;
+use fsapi::node::NavState;
+use fsapi::{FsApi, Node};
+use tokio::sync::watch;
 
-use fsapi::{FsApi, Node, Value};
-use tokio::sync::Mutex;
+use crate::mode::Mode;
+use crate::{Error, Radio};
 
-use crate::Error;
+pub use browser::{NavEntry, NavEntryKind};
+pub use preset::{Preset, Presets};
+
+mod browser;
+pub(crate) mod path;
+mod preset;
 
 #[derive(Debug)]
 pub struct Nav {
-    pub state: Mutex<bool>,
-    pub presets: Mutex<Vec<String>>,
+    /// Whether the navigation is enabled,
+    /// the radio disables it on every mode change
+    pub(crate) state: watch::Sender<bool>,
+
+    /// Whether the menu is ready to be browsed, see [`Node::NavStatus`]
+    pub(crate) ready: watch::Sender<bool>,
+
+    /// Listed for the mode at connect and on demand for the others
+    pub(crate) presets: watch::Sender<Presets>,
+}
+
+impl Radio {
+    pub fn subscribe_nav_state(&self) -> watch::Receiver<bool> {
+        self.nav.state.subscribe()
+    }
+
+    pub fn subscribe_nav_ready(&self) -> watch::Receiver<bool> {
+        self.nav.ready.subscribe()
+    }
 }
 
 impl Nav {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        FsApi::set(Node::NavState, 1, &host, pin).await?;
+    pub async fn new(fsapi: &FsApi, mode: Mode) -> Result<Self, Error> {
+        fsapi.set_typed::<NavState>(true).await?;
         let state = true;
 
-        let mut presets: Vec<String> = Vec::new();
-        for preset in FsApi::get_item_list(Node::NavPresets, None, &host, pin).await? {
-            match &preset.fields[0].value {
-                Value::Text(ref s) if !s.is_empty() => presets.push(s.into()),
-                Value::Text(_) => break,
-                _ => panic!("NavPresets return a Vec<Field<Value::Text>>>"),
-            };
-        }
+        let items = fsapi.get_item_list(Node::NavPresets, None).await?;
+        let presets = Presets::from([(mode, Preset::from_items(items, mode)?)]);
 
         Ok(Self {
-            state: Mutex::new(state),
-            presets: Mutex::new(presets),
+            state: watch::channel(state).0,
+            ready: watch::channel(true).0,
+            presets: watch::channel(presets).0,
         })
     }
 
-    pub async fn preset_select<D: Display>(preset: u32, host: D, pin: u32) -> Result<(), Error> {
-        FsApi::set(Node::NavState, preset, &host, pin).await?;
+    /// Whether the navigation is enabled
+    pub fn state(&self) -> bool {
+        *self.state.borrow()
+    }
 
-        Ok(())
+    /// Whether the menu is ready to be browsed
+    pub fn ready(&self) -> bool {
+        *self.ready.borrow()
     }
 }
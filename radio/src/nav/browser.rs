@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use fsapi::node::{
+    NavActionNavigate, NavActionSelectItem, NavDepth, NavSearchTerm, NavState, NavStatus,
+};
+use fsapi::{Item, Node};
+use tokio::time::Instant;
+
+use crate::{update, Error, Radio};
+
+/// How long [`Radio::nav_wait_ready`] waits for the menu
+pub const NAV_READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often [`Radio::nav_wait_ready`] asks the radio while waiting
+const NAV_READY_POLL: Duration = Duration::from_millis(250);
+
+/// How long a menu action may take to report busy, after that a ready menu is trusted
+const NAV_BUSY_GRACE: Duration = Duration::from_secs(1);
+
+/// An entry of the current folder of the menu, see [`Radio::nav_list`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavEntry {
+    pub key: u32,
+    pub name: String,
+    pub kind: NavEntryKind,
+
+    /// What kind of folder or item, depends on the mode
+    pub subtype: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavEntryKind {
+    /// Opened with [`Radio::nav_enter`]
+    Folder,
+
+    /// Played with [`Radio::nav_select`]
+    Playable,
+}
+
+impl NavEntry {
+    /// Reads the `name`, `type` and `subtype` fields of a [`Node::NavList`] item
+    fn from_item(item: Item) -> Result<Self, Error> {
+        let mut entry = Self {
+            key: item.key,
+            name: String::new(),
+            kind: NavEntryKind::Playable,
+            subtype: 0,
+        };
+
+        for field in item.fields {
+            match field.name.as_str() {
+                "name" => entry.name = field.value.into_text()?,
+                "type" if field.value.as_u8()? == 0 => entry.kind = NavEntryKind::Folder,
+                "subtype" => entry.subtype = field.value.as_u8()?,
+                _ => (),
+            }
+        }
+
+        Ok(entry)
+    }
+}
+
+impl Radio {
+    /// The entries of the current folder
+    ///
+    /// Enables the navigation if needed and waits until the menu is ready.
+    pub async fn nav_list(&self) -> Result<Vec<NavEntry>, Error> {
+        self.nav_enable().await?;
+        self.nav_wait_ready().await?;
+
+        self.session
+            .get_item_list(Node::NavList)
+            .await?
+            .into_iter()
+            .map(NavEntry::from_item)
+            .collect()
+    }
+
+    /// Opens the folder with `key`
+    pub async fn nav_enter(&self, key: u32) -> Result<(), Error> {
+        let key = i32::try_from(key).map_err(|_| Error::InvalidValue)?;
+
+        self.nav_navigate(key).await
+    }
+
+    /// Goes back to the parent folder
+    pub async fn nav_up(&self) -> Result<(), Error> {
+        self.nav_navigate(-1).await
+    }
+
+    /// Plays the item with `key`
+    pub async fn nav_select(&self, key: u32) -> Result<(), Error> {
+        self.nav_enable().await?;
+        self.fsapi.set_typed::<NavActionSelectItem>(key).await?;
+
+        Ok(())
+    }
+
+    /// Enters a folder or plays an item, depending on its kind
+    pub async fn nav_open(&self, entry: &NavEntry) -> Result<(), Error> {
+        match entry.kind {
+            NavEntryKind::Folder => self.nav_enter(entry.key).await,
+            NavEntryKind::Playable => self.nav_select(entry.key).await,
+        }
+    }
+
+    /// Searches the current folder for `term`
+    ///
+    /// Only works in menus with a search, like internet radio or Spotify.
+    /// Returns the results, open them with [`Radio::nav_open`].
+    pub async fn nav_search(&self, term: &str) -> Result<Vec<NavEntry>, Error> {
+        self.nav_enable().await?;
+
+        update(&self.nav.ready, false);
+        self.fsapi
+            .set_typed::<NavSearchTerm>(term.to_string())
+            .await?;
+        self.nav_wait_action().await?;
+
+        self.nav_list().await
+    }
+
+    /// How deep the current folder is, `0` is the root
+    pub async fn nav_depth(&self) -> Result<u8, Error> {
+        Ok(self.fsapi.get_typed::<NavDepth>().await?)
+    }
+
+    /// Waits until the radio reports that the menu is ready
+    ///
+    /// Fails with [`Error::Timeout`] after [`NAV_READY_TIMEOUT`].
+    pub async fn nav_wait_ready(&self) -> Result<(), Error> {
+        let deadline = Instant::now() + NAV_READY_TIMEOUT;
+        let mut ready = self.subscribe_nav_ready();
+
+        loop {
+            let status = self.fsapi.get_typed::<NavStatus>().await?;
+            update(&self.nav.ready, status == 1);
+            if status == 1 {
+                return Ok(());
+            }
+
+            // Notifications are faster, if they are handled at all
+            tokio::select! {
+                _ = tokio::time::sleep_until(deadline) => return Err(Error::Timeout),
+                _ = ready.wait_for(|ready| *ready) => (),
+                _ = tokio::time::sleep(NAV_READY_POLL) => (),
+            }
+        }
+    }
+
+    async fn nav_navigate(&self, key: i32) -> Result<(), Error> {
+        self.nav_enable().await?;
+
+        update(&self.nav.ready, false);
+        self.fsapi.set_typed::<NavActionNavigate>(key).await?;
+
+        self.nav_wait_action().await
+    }
+
+    /// Waits until the menu finished an action that was just sent
+    ///
+    /// The radio takes a moment to report busy, so a ready menu only counts
+    /// once it was busy, after a ready notification or after [`NAV_BUSY_GRACE`].
+    /// Expects [`Nav::ready`](super::Nav::ready) to be reset before the action.
+    async fn nav_wait_action(&self) -> Result<(), Error> {
+        let start = Instant::now();
+        let deadline = start + NAV_READY_TIMEOUT;
+        let mut ready = self.subscribe_nav_ready();
+        let mut busy = false;
+
+        loop {
+            // Only a notification sets it while waiting
+            if *ready.borrow_and_update() {
+                return Ok(());
+            }
+
+            let status = self.fsapi.get_typed::<NavStatus>().await?;
+            if status != 1 {
+                busy = true;
+            } else if busy || start.elapsed() >= NAV_BUSY_GRACE {
+                update(&self.nav.ready, true);
+                return Ok(());
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep_until(deadline) => return Err(Error::Timeout),
+                _ = ready.wait_for(|ready| *ready) => (),
+                _ = tokio::time::sleep(NAV_READY_POLL) => (),
+            }
+        }
+    }
+
+    /// Turns on the navigation, the radio turns it off on every mode change
+    pub(crate) async fn nav_enable(&self) -> Result<(), Error> {
+        if !self.nav.state() {
+            self.fsapi.set_typed::<NavState>(true).await?;
+            update(&self.nav.state, true);
+        }
+
+        Ok(())
+    }
+}
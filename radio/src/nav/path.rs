@@ -0,0 +1,197 @@
+use super::{NavEntry, NavEntryKind};
+use crate::{Error, Radio};
+
+impl Radio {
+    /// Walks the menu from the root along the folder names in `path`
+    ///
+    /// Names are matched exactly, then ignoring case and finally fuzzy,
+    /// so `"bbc radio4"` finds `"BBC Radio 4"`. Every segment but the last
+    /// has to be a folder. The last one is entered if it is a folder,
+    /// or played if it is not, and returned.
+    ///
+    /// Fails with [`Error::PathNotFound`] naming the first segment without a match.
+    pub async fn nav_navigate_path<S: AsRef<str>>(&self, path: &[S]) -> Result<NavEntry, Error> {
+        let Some((last, folders)) = path.split_last() else {
+            return Err(Error::InvalidValue);
+        };
+
+        self.nav_root().await?;
+
+        let mut walked = Vec::new();
+        for segment in folders {
+            let segment = segment.as_ref();
+            let entries = self.nav_list().await?;
+            let folder = find(&entries, segment, Some(NavEntryKind::Folder))
+                .ok_or_else(|| not_found(segment, &walked))?;
+
+            self.nav_enter(folder.key).await?;
+            walked.push(folder.name.clone());
+        }
+
+        let last = last.as_ref();
+        let entries = self.nav_list().await?;
+        let entry = find(&entries, last, None)
+            .ok_or_else(|| not_found(last, &walked))?
+            .clone();
+        self.nav_open(&entry).await?;
+
+        Ok(entry)
+    }
+
+    /// Goes up until the menu is at its root
+    ///
+    /// Fails with [`Error::NavStuck`] if the root isn't reached
+    /// after going up as often as the menu was deep.
+    async fn nav_root(&self) -> Result<(), Error> {
+        let depth = self.nav_depth().await?;
+
+        for _ in 0..depth {
+            self.nav_up().await?;
+        }
+
+        match self.nav_depth().await? {
+            0 => Ok(()),
+            depth => Err(Error::NavStuck { depth }),
+        }
+    }
+}
+
+fn not_found(segment: &str, walked: &[String]) -> Error {
+    Error::PathNotFound {
+        segment: segment.to_string(),
+        folder: walked.join("/"),
+    }
+}
+
+/// The entry named most like `name`, only of `kind` if given
+pub(crate) fn find<'a>(
+    entries: &'a [NavEntry],
+    name: &str,
+    kind: Option<NavEntryKind>,
+) -> Option<&'a NavEntry> {
+    let candidates = || {
+        entries
+            .iter()
+            .filter(move |entry| kind.is_none_or(|kind| entry.kind == kind))
+    };
+
+    if let Some(entry) = candidates().find(|entry| entry.name == name) {
+        return Some(entry);
+    }
+
+    if let Some(entry) = candidates().find(|entry| entry.name.eq_ignore_ascii_case(name)) {
+        return Some(entry);
+    }
+
+    let name = normalize(name);
+    if name.is_empty() {
+        return None;
+    }
+
+    // Allow about one typo per four characters
+    let max_distance = name.chars().count() / 4;
+
+    candidates()
+        .filter_map(|entry| {
+            let candidate = normalize(&entry.name);
+            let distance = match candidate.contains(&name) {
+                true => 0,
+                false => distance(&candidate, &name),
+            };
+            (distance <= max_distance).then_some((distance, candidate.len(), entry))
+        })
+        .min_by_key(|(distance, len, _)| (*distance, *len))
+        .map(|(_, _, entry)| entry)
+}
+
+/// Lowercase letters and digits only, so spacing and punctuation don't matter
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Levenshtein distance between `a` and `b`
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: u32, name: &str, kind: NavEntryKind) -> NavEntry {
+        NavEntry {
+            key,
+            name: name.to_string(),
+            kind,
+            subtype: 0,
+        }
+    }
+
+    fn entries() -> Vec<NavEntry> {
+        vec![
+            entry(0, "BBC Radio 4", NavEntryKind::Playable),
+            entry(1, "bbc radio 4", NavEntryKind::Folder),
+            entry(2, "Jazz FM", NavEntryKind::Playable),
+            entry(3, "Stations", NavEntryKind::Folder),
+        ]
+    }
+
+    fn key(name: &str, kind: Option<NavEntryKind>) -> Option<u32> {
+        find(&entries(), name, kind).map(|entry| entry.key)
+    }
+
+    #[test]
+    fn exact_match_comes_first() {
+        assert_eq!(key("bbc radio 4", None), Some(1));
+        assert_eq!(key("BBC Radio 4", None), Some(0));
+    }
+
+    #[test]
+    fn case_is_ignored() {
+        assert_eq!(key("JAZZ fm", None), Some(2));
+    }
+
+    #[test]
+    fn fuzzy_names_match() {
+        assert_eq!(key("jazz-fm", None), Some(2));
+        assert_eq!(key("jazz", None), Some(2));
+        assert_eq!(key("Statoins", None), Some(3));
+    }
+
+    #[test]
+    fn kind_filters_entries() {
+        assert_eq!(key("BBC Radio 4", Some(NavEntryKind::Folder)), Some(1));
+        assert_eq!(key("Jazz FM", Some(NavEntryKind::Folder)), None);
+    }
+
+    #[test]
+    fn about_one_typo_per_four_characters() {
+        // "stations" has 8 characters, so 2 typos are allowed
+        assert_eq!(key("staxxons", None), Some(3));
+        assert_eq!(key("staxxxns", None), None);
+        // Less than 4 characters allow no typo at all
+        assert_eq!(key("jaz", None), Some(2));
+        assert_eq!(key("jzz", None), None);
+    }
+
+    #[test]
+    fn empty_names_match_nothing() {
+        assert_eq!(key("", None), None);
+        assert_eq!(key("- -", None), None);
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use fsapi::node::{NavActionSelectPreset, PlayAddPreset, PlayInfoName};
+use fsapi::{FsApi, Item, Node, Value};
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+use super::Nav;
+use crate::mode::Mode;
+use crate::player::{PlayerCapabilities, Status};
+use crate::{Error, Radio};
+
+/// Presets of every mode that was listed, the radio keeps a list per mode
+pub type Presets = HashMap<Mode, Vec<Preset>>;
+
+/// How long to wait for a station to start playing
+pub const TUNE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How often to check whether the station plays while tuning
+const TUNE_POLL: Duration = Duration::from_millis(500);
+
+/// How long the radio may keep playing the same station before it is
+/// taken as the station that was asked for
+const TUNE_GRACE: Duration = Duration::from_secs(3);
+
+/// A favorite station, see [`Node::NavPresets`](fsapi::Node::NavPresets)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Preset {
+    /// Key of the item, used to select or store the preset
+    pub slot: u32,
+
+    /// Empty for an unused slot
+    pub name: String,
+
+    /// The mode the preset belongs to
+    pub kind: Mode,
+}
+
+impl Preset {
+    /// Whether nothing is stored in the slot
+    pub fn is_empty(&self) -> bool {
+        self.name.is_empty()
+    }
+
+    /// Reads the `name` field, or the first text field of older firmware
+    fn from_item(item: Item, kind: Mode) -> Result<Self, Error> {
+        let field = item
+            .fields
+            .iter()
+            .find(|field| field.name == "name")
+            .or_else(|| {
+                item.fields
+                    .iter()
+                    .find(|field| matches!(field.value, Value::Text(_)))
+            });
+        let name = match field {
+            Some(field) => field.value.as_text()?.to_string(),
+            None => String::new(),
+        };
+
+        Ok(Self {
+            slot: item.key,
+            name,
+            kind,
+        })
+    }
+
+    /// Every slot of a preset list, including the empty ones
+    pub(crate) fn from_items(items: Vec<Item>, kind: Mode) -> Result<Vec<Self>, Error> {
+        items
+            .into_iter()
+            .map(|item| Self::from_item(item, kind))
+            .collect()
+    }
+}
+
+impl Radio {
+    /// The presets of the current mode, listed once per mode
+    pub async fn presets(&self) -> Result<Vec<Preset>, Error> {
+        match self.nav.presets(self.mode()) {
+            Some(presets) => Ok(presets),
+            None => self.presets_refresh().await,
+        }
+    }
+
+    /// Lists the presets of the current mode again
+    pub async fn presets_refresh(&self) -> Result<Vec<Preset>, Error> {
+        let mode = self.mode();
+
+        self.nav_enable().await?;
+        let items = self.session.get_item_list(Node::NavPresets).await?;
+        let presets = Preset::from_items(items, mode)?;
+
+        self.nav.presets.send_modify(|all| {
+            all.insert(mode, presets.clone());
+        });
+
+        Ok(presets)
+    }
+
+    /// Plays the preset in `slot` of the current mode
+    pub async fn preset_select(&self, slot: u32) -> Result<(), Error> {
+        self.nav_enable().await?;
+
+        Nav::preset_select(slot, &self.fsapi).await
+    }
+
+    /// Plays the preset in `slot` and waits until its station plays
+    ///
+    /// Fails with [`Error::Timeout`] after [`TUNE_TIMEOUT`].
+    pub async fn preset_play(&self, slot: u32) -> Result<(), Error> {
+        let playing = self.fsapi.get_typed::<PlayInfoName>().await.ok();
+
+        self.preset_select(slot).await?;
+        self.player_wait_tuned(playing).await
+    }
+
+    /// Stores the current station in `slot`, replacing what was there
+    pub async fn preset_store(&self, slot: u32) -> Result<Vec<Preset>, Error> {
+        self.player_require(PlayerCapabilities::ADD_PRESET, "store preset")?;
+
+        self.fsapi.set_typed::<PlayAddPreset>(slot).await?;
+
+        self.presets_refresh().await
+    }
+
+    /// Copies the preset in `from` to `to`, replacing whatever was in `to`
+    ///
+    /// Changes what is playing: FSAPI can only store the playing station,
+    /// so the station of `from` is played first and keeps playing.
+    /// `from` keeps its station, presets can't be moved or cleared.
+    /// Reordering is left to the caller, e.g. copying through a free slot.
+    pub async fn preset_copy(&self, from: u32, to: u32) -> Result<Vec<Preset>, Error> {
+        self.player_require(PlayerCapabilities::ADD_PRESET, "copy preset")?;
+
+        self.preset_play(from).await?;
+        self.preset_store(to).await
+    }
+
+    /// Waits until a station plays after tuning away from the one named `playing`
+    ///
+    /// The radio keeps reporting the old station for a moment. So a station
+    /// only counts once the radio stopped or the name changed, or after
+    /// [`TUNE_GRACE`] in case the same station was tuned again.
+    pub(crate) async fn player_wait_tuned(&self, playing: Option<String>) -> Result<(), Error> {
+        let start = Instant::now();
+        let mut stopped = false;
+
+        loop {
+            if Status::get(&self.fsapi).await? != Status::Playing {
+                stopped = true;
+            } else {
+                let name = self.fsapi.get_typed::<PlayInfoName>().await.ok();
+                if stopped || name != playing || start.elapsed() >= TUNE_GRACE {
+                    return Ok(());
+                }
+            }
+
+            if start.elapsed() >= TUNE_TIMEOUT {
+                return Err(Error::Timeout);
+            }
+
+            tokio::time::sleep(TUNE_POLL).await;
+        }
+    }
+
+    pub fn subscribe_presets(&self) -> watch::Receiver<Presets> {
+        self.nav.presets.subscribe()
+    }
+}
+
+impl Nav {
+    /// The cached presets of `mode`, `None` if they were never listed
+    pub fn presets(&self, mode: Mode) -> Option<Vec<Preset>> {
+        self.presets.borrow().get(&mode).cloned()
+    }
+
+    pub async fn preset_select(slot: u32, fsapi: &FsApi) -> Result<(), Error> {
+        fsapi.set_typed::<NavActionSelectPreset>(slot).await?;
+
+        Ok(())
+    }
+}
@@ -1,54 +1,184 @@
-use std::fmt::Display;
+use std::time::Duration;
 
+use fsapi::node::{PlayCaps, PlayPosition, PlayRate, PlayRepeat, PlayShuffle};
 use fsapi::{FsApi, Node};
-use tokio::sync::Mutex;
+use tokio::sync::watch;
 
-use crate::Error;
+use crate::{take_typed, take_value, update, Error, Radio, Values};
 use info::PlayerInfo;
-use status::Status;
 
+pub use artwork::{Artwork, ArtworkCache};
+pub use capabilities::PlayerCapabilities;
+pub use control::{Control, ControlNode};
+pub use info::NowPlaying;
+pub use position::Position;
+pub use status::Status;
+
+mod artwork;
+mod capabilities;
+mod control;
 mod info;
+mod position;
 mod status;
 
 #[derive(Debug)]
 pub struct Player {
     pub info: PlayerInfo,
-    pub status: Mutex<Status>,
+    pub(crate) status: watch::Sender<Status>,
+
+    /// Not sent as a notification, kept up to date by [`Radio::spawn_sync`]
+    pub(crate) position: watch::Sender<Position>,
+
+    /// Not sent as a notification, kept up to date by [`Radio::spawn_sync`]
+    pub(crate) signal_strength: watch::Sender<u8>,
+
+    pub(crate) shuffle: watch::Sender<bool>,
+    pub(crate) repeat: watch::Sender<bool>,
+
+    /// Playback speed, negative while rewinding
+    pub(crate) rate: watch::Sender<i8>,
+
+    /// What the current mode can do, refreshed on every mode change
+    pub(crate) capabilities: watch::Sender<PlayerCapabilities>,
+
+    pub(crate) artwork: ArtworkCache,
+}
+
+impl Radio {
+    pub fn subscribe_now_playing(&self) -> watch::Receiver<NowPlaying> {
+        self.player.info.now_playing.subscribe()
+    }
+
+    pub fn subscribe_play_status(&self) -> watch::Receiver<Status> {
+        self.player.status.subscribe()
+    }
+
+    pub fn subscribe_signal_strength(&self) -> watch::Receiver<u8> {
+        self.player.signal_strength.subscribe()
+    }
 }
 
 impl Player {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let info = PlayerInfo::new(&host, pin).await?;
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
+
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        let mut nodes = PlayerInfo::nodes();
+        nodes.extend([
+            Node::PlayStatus,
+            Node::PlayShuffle,
+            Node::PlayRepeat,
+            Node::PlayRate,
+            Node::PlayCaps,
+        ]);
+        nodes
+    }
+
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let info = PlayerInfo::from_values(values)?;
+
+        let status = Status::from_value(take_value(values, Node::PlayStatus)?)?;
+
+        // Blocked in modes that can't shuffle, repeat or seek, like fm
+        let shuffle = take_typed::<PlayShuffle>(values).unwrap_or_default();
+        let repeat = take_typed::<PlayRepeat>(values).unwrap_or_default();
+        let rate = take_typed::<PlayRate>(values).unwrap_or(1);
 
-        let status = Status::get(&host, pin).await?;
+        let capabilities = take_typed::<PlayCaps>(values)
+            .map(PlayerCapabilities::from_bits)
+            .unwrap_or(PlayerCapabilities::ALL);
 
         Ok(Self {
             info,
-            status: Mutex::new(status),
+            status: watch::channel(status).0,
+            position: watch::channel(Position::new(Duration::ZERO)).0,
+            signal_strength: watch::channel(0).0,
+            shuffle: watch::channel(shuffle).0,
+            repeat: watch::channel(repeat).0,
+            rate: watch::channel(rate).0,
+            capabilities: watch::channel(capabilities).0,
+            artwork: ArtworkCache::default(),
         })
     }
 
-    async fn control_set<D: Display, O: Display>(
-        option: O,
-        host: D,
-        pin: u32,
-    ) -> Result<(), Error> {
-        FsApi::set(Node::PlayControl, option, &host, pin).await?;
+    pub fn status(&self) -> Status {
+        *self.status.borrow()
+    }
+
+    /// Zero until the first sync, or if the mode has no signal
+    pub fn signal_strength(&self) -> u8 {
+        *self.signal_strength.borrow()
+    }
+
+    pub fn shuffle(&self) -> bool {
+        *self.shuffle.borrow()
+    }
+
+    pub fn repeat(&self) -> bool {
+        *self.repeat.borrow()
+    }
+
+    pub fn rate(&self) -> i8 {
+        *self.rate.borrow()
+    }
+
+    pub fn capabilities(&self) -> PlayerCapabilities {
+        *self.capabilities.borrow()
+    }
+
+    pub async fn control(control: Control, fsapi: &FsApi) -> Result<(), Error> {
+        fsapi.set_typed::<ControlNode>(control).await?;
 
         Ok(())
     }
 
-    pub async fn toggle<D: Display>(host: D, pin: u32) -> Result<(), Error> {
-        Self::control_set(0, host, pin).await
+    pub async fn toggle(fsapi: &FsApi) -> Result<(), Error> {
+        Self::control(Control::Toggle, fsapi).await
+    }
+
+    pub async fn next(fsapi: &FsApi) -> Result<(), Error> {
+        Self::control(Control::Next, fsapi).await
+    }
+
+    pub async fn prev(fsapi: &FsApi) -> Result<(), Error> {
+        Self::control(Control::Previous, fsapi).await
+    }
+
+    pub async fn seek(&self, position: Duration, fsapi: &FsApi) -> Result<(), Error> {
+        fsapi.set_typed::<PlayPosition>(position).await?;
+
+        self.update_position(position);
+
+        Ok(())
     }
 
-    pub async fn next<D: Display>(host: D, pin: u32) -> Result<(), Error> {
-        Self::control_set(3, host, pin).await
+    pub async fn set_shuffle(&self, shuffle: bool, fsapi: &FsApi) -> Result<(), Error> {
+        fsapi.set_typed::<PlayShuffle>(shuffle).await?;
+
+        update(&self.shuffle, shuffle);
+
+        Ok(())
     }
 
-    pub async fn prev<D: Display>(host: D, pin: u32) -> Result<(), Error> {
-        Self::control_set(4, host, pin).await
+    pub async fn set_repeat(&self, repeat: bool, fsapi: &FsApi) -> Result<(), Error> {
+        fsapi.set_typed::<PlayRepeat>(repeat).await?;
+
+        update(&self.repeat, repeat);
+
+        Ok(())
     }
 
-    // TODO: I think there are more options than just: toggle, next, prev
+    pub async fn set_rate(&self, rate: i8, fsapi: &FsApi) -> Result<(), Error> {
+        if rate == i8::MIN {
+            return Err(Error::InvalidValue);
+        }
+
+        fsapi.set_typed::<PlayRate>(rate).await?;
+
+        self.update_rate(rate);
+
+        Ok(())
+    }
 }
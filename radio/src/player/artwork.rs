@@ -0,0 +1,218 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use fsapi::FsApi;
+use tokio::sync::Mutex;
+
+use super::Player;
+use crate::{Error, Radio};
+
+/// Default limit of [`ArtworkCache::default`], 32 MiB
+pub const DEFAULT_CACHE_SIZE: u64 = 32 * 1024 * 1024;
+
+/// Largest artwork that is downloaded, 4 MiB
+pub const MAX_ARTWORK_SIZE: u64 = 4 * 1024 * 1024;
+
+/// An image downloaded from [`Node::PlayInfoGraphicUri`](fsapi::Node::PlayInfoGraphicUri)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artwork {
+    pub bytes: Arc<[u8]>,
+
+    /// Detected from the content, the radio does not send it
+    pub mime: &'static str,
+}
+
+impl Artwork {
+    fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            mime: detect_mime(&bytes),
+            bytes: bytes.into(),
+        }
+    }
+}
+
+/// Keeps downloaded artwork on disk, keyed by its uri
+///
+/// When the cache grows over its size limit the files used longest ago are removed.
+/// The current artwork is also kept in memory.
+#[derive(Debug)]
+pub struct ArtworkCache {
+    /// `None` to only keep the current artwork in memory
+    dir: Option<PathBuf>,
+    max_size: u64,
+
+    /// The uri and artwork that were returned last
+    current: Mutex<Option<(String, Artwork)>>,
+}
+
+impl Default for ArtworkCache {
+    /// A cache of [`DEFAULT_CACHE_SIZE`] in the cache directory of the user
+    ///
+    /// Falls back to the temp directory, which may be shared with other users,
+    /// if the platform has no cache directory.
+    fn default() -> Self {
+        let dir = dirs::cache_dir().unwrap_or_else(std::env::temp_dir);
+
+        Self::on_disk(dir.join("radio-artwork"), DEFAULT_CACHE_SIZE)
+    }
+}
+
+impl ArtworkCache {
+    /// A cache in `dir` of at most `max_size` bytes, `dir` is created when needed
+    pub fn on_disk<P: Into<PathBuf>>(dir: P, max_size: u64) -> Self {
+        Self {
+            dir: Some(dir.into()),
+            max_size,
+            current: Mutex::new(None),
+        }
+    }
+
+    /// A cache that only keeps the current artwork
+    pub fn in_memory() -> Self {
+        Self {
+            dir: None,
+            max_size: 0,
+            current: Mutex::new(None),
+        }
+    }
+
+    /// The artwork behind `uri`, from the cache or downloaded
+    ///
+    /// Artwork over [`MAX_ARTWORK_SIZE`] fails with [`fsapi::Error::TooLarge`] in [`Error::FsApi`].
+    pub async fn get(&self, uri: &str, fsapi: &FsApi) -> Result<Artwork, Error> {
+        // Held during the download, so the same artwork is only downloaded once
+        let mut current = self.current.lock().await;
+        if let Some((current_uri, artwork)) = current.as_ref() {
+            if current_uri == uri {
+                return Ok(artwork.clone());
+            }
+        }
+
+        let artwork = match self.read(uri).await {
+            Some(bytes) => Artwork::new(bytes),
+            None => {
+                let bytes = fsapi.download(uri, MAX_ARTWORK_SIZE).await?;
+                // The artwork is still usable without the cache
+                let _ = self.write(uri, &bytes).await;
+                Artwork::new(bytes)
+            }
+        };
+
+        *current = Some((uri.to_string(), artwork.clone()));
+
+        Ok(artwork)
+    }
+
+    fn path(&self, uri: &str) -> Option<PathBuf> {
+        Some(self.dir.as_ref()?.join(format!("{:016x}", fnv1a(uri))))
+    }
+
+    async fn read(&self, uri: &str) -> Option<Vec<u8>> {
+        let path = self.path(uri)?;
+        let bytes = tokio::fs::read(&path).await.ok()?;
+
+        // Mark as recently used for the eviction
+        let _ = tokio::task::spawn_blocking(move || {
+            std::fs::File::options()
+                .append(true)
+                .open(path)?
+                .set_modified(SystemTime::now())
+        })
+        .await;
+
+        Some(bytes)
+    }
+
+    async fn write(&self, uri: &str, bytes: &[u8]) -> io::Result<()> {
+        let (Some(dir), Some(path)) = (&self.dir, self.path(uri)) else {
+            return Ok(());
+        };
+        if bytes.len() as u64 > self.max_size {
+            return Ok(());
+        }
+
+        tokio::fs::create_dir_all(dir).await?;
+        tokio::fs::write(path, bytes).await?;
+
+        evict(dir, self.max_size).await
+    }
+}
+
+/// Removes the files used longest ago until `dir` fits in `max_size`
+async fn evict(dir: &Path, max_size: u64) -> io::Result<()> {
+    let mut files = Vec::new();
+    let mut entries = tokio::fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let metadata = entry.metadata().await?;
+        if metadata.is_file() {
+            files.push((metadata.modified()?, metadata.len(), entry.path()));
+        }
+    }
+
+    let mut size: u64 = files.iter().map(|(_, len, _)| len).sum();
+    files.sort();
+
+    for (_, len, path) in files {
+        if size <= max_size {
+            break;
+        }
+
+        tokio::fs::remove_file(path).await?;
+        size -= len;
+    }
+
+    Ok(())
+}
+
+/// A hash that stays the same between runs, used for the file names
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Detects the image format by its magic bytes
+fn detect_mime(bytes: &[u8]) -> &'static str {
+    match bytes {
+        [0x89, b'P', b'N', b'G', ..] => "image/png",
+        [0xFF, 0xD8, 0xFF, ..] => "image/jpeg",
+        [b'G', b'I', b'F', b'8', ..] => "image/gif",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => "image/webp",
+        [b'B', b'M', ..] => "image/bmp",
+        _ => "application/octet-stream",
+    }
+}
+
+impl Radio {
+    /// Uses `cache` for the artwork instead of [`ArtworkCache::default`]
+    pub fn with_artwork_cache(mut self, cache: ArtworkCache) -> Self {
+        self.player.artwork = cache;
+        self
+    }
+
+    /// See [`Player::artwork`]
+    pub async fn player_artwork(&self) -> Result<Option<Artwork>, Error> {
+        self.player.artwork(&self.fsapi).await
+    }
+
+    /// Re-reads the artwork uri after the track or mode changed
+    ///
+    /// Not every station sends a new uri, so the old one would stick around.
+    pub(crate) async fn player_refresh_graphic_uri(&self) {
+        self.player.info.refresh_graphic_uri(&self.fsapi).await;
+    }
+}
+
+impl Player {
+    /// The artwork of the current track or station, `None` if it has none
+    pub async fn artwork(&self, fsapi: &FsApi) -> Result<Option<Artwork>, Error> {
+        let uri = self.info.now_playing().graphic_uri;
+        if uri.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(self.artwork.get(&uri, fsapi).await?))
+    }
+}
@@ -0,0 +1,125 @@
+use std::fmt;
+use std::ops::BitOr;
+
+use fsapi::node::PlayCaps;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+use crate::{update, Error, Radio};
+
+/// What the player can do in the current mode, decoded from [`PlayCaps`]
+///
+/// Only the bits documented on [`PlayCaps`] are named. The docs are unsure
+/// which of bits 0 and 1 is play and which is pause, and the same for next
+/// and previous in bits 2 and 3. So [`Radio::player_toggle`] is only refused
+/// when neither play nor pause is set, and skipping when neither skip bit is.
+/// Seek, shuffle, repeat and the rate are never refused, their bits are unknown.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerCapabilities(u32);
+
+impl PlayerCapabilities {
+    pub const NONE: Self = Self(0);
+    pub const PLAY: Self = Self(1 << 0);
+    pub const PAUSE: Self = Self(1 << 1);
+    pub const SKIP_NEXT: Self = Self(1 << 2);
+    pub const SKIP_PREVIOUS: Self = Self(1 << 3);
+    pub const ADD_PRESET: Self = Self(1 << 11);
+
+    /// Either bit may be play or pause
+    pub(crate) const PLAY_PAUSE: Self = Self(Self::PLAY.0 | Self::PAUSE.0);
+
+    /// Either bit may be next or previous
+    pub(crate) const SKIP: Self = Self(Self::SKIP_NEXT.0 | Self::SKIP_PREVIOUS.0);
+
+    /// Used while the capabilities are unknown, so nothing gets blocked
+    pub const ALL: Self = Self(u32::MAX);
+
+    const NAMED: [(Self, &'static str); 5] = [
+        (Self::PLAY, "PLAY"),
+        (Self::PAUSE, "PAUSE"),
+        (Self::SKIP_NEXT, "SKIP_NEXT"),
+        (Self::SKIP_PREVIOUS, "SKIP_PREVIOUS"),
+        (Self::ADD_PRESET, "ADD_PRESET"),
+    ];
+
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Whether every capability of `other` is in `self`
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether any capability of `other` is in `self`
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for PlayerCapabilities {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl fmt::Debug for PlayerCapabilities {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if *self == Self::ALL {
+            return write!(f, "PlayerCapabilities(ALL)");
+        }
+
+        let names = Self::NAMED
+            .iter()
+            .filter(|(capability, _)| self.contains(*capability))
+            .map(|(_, name)| *name);
+        f.debug_tuple("PlayerCapabilities")
+            .field(&names.collect::<Vec<_>>().join(" | "))
+            .finish()
+    }
+}
+
+impl Radio {
+    pub fn subscribe_capabilities(&self) -> watch::Receiver<PlayerCapabilities> {
+        self.player.capabilities.subscribe()
+    }
+
+    /// Fetches the capabilities of the current mode
+    ///
+    /// When they can't be fetched every action is allowed
+    /// and the radio decides on its own.
+    pub async fn player_refresh_capabilities(&self) -> PlayerCapabilities {
+        let capabilities = match self.fsapi.get_typed::<PlayCaps>().await {
+            Ok(bits) => PlayerCapabilities::from_bits(bits),
+            Err(_) => PlayerCapabilities::ALL,
+        };
+
+        update(&self.player.capabilities, capabilities);
+
+        capabilities
+    }
+
+    /// Fails with [`Error::Unsupported`] when the current mode has none of `capability`
+    ///
+    /// Pass every bit that may stand for `action`, see [`PlayerCapabilities`].
+    pub(crate) fn player_require(
+        &self,
+        capability: PlayerCapabilities,
+        action: &'static str,
+    ) -> Result<(), Error> {
+        if self.player.capabilities().intersects(capability) {
+            Ok(())
+        } else {
+            Err(Error::Unsupported {
+                action,
+                mode: self.mode(),
+            })
+        }
+    }
+}
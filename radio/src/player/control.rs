@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use fsapi::{Error as FsApiError, Node, TypedNode, Value, WritableNode};
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+use crate::{Error, Radio};
+
+/// Value of [`Node::PlayControl`]
+///
+/// Only [`Control::Toggle`], [`Control::Next`] and [`Control::Previous`]
+/// were verified on a radio, so [`Radio`] only sends those.
+/// Play and pause are documented as wrong on [`Node::PlayControl`],
+/// they are kept to read the value back. No value is known to stop playback.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum Control {
+    /// Toggles between playing and paused
+    Toggle = 0,
+
+    /// Documented as wrong, do not send
+    Play = 1,
+
+    /// Documented as wrong, do not send
+    Pause = 2,
+
+    /// Next song or station
+    Next = 3,
+
+    /// Previous song or station
+    Previous = 4,
+}
+
+/// Typed marker for [`Node::PlayControl`] as a [`Control`]
+#[derive(Clone, Copy, Debug)]
+pub struct ControlNode;
+
+impl TypedNode for ControlNode {
+    const NODE: Node = Node::PlayControl;
+
+    type Type = Control;
+
+    fn decode(value: Value) -> Result<Control, FsApiError> {
+        Control::try_from(value.as_u8()?).map_err(|found| FsApiError::OutOfRange {
+            node: Node::PlayControl,
+            value: found.into(),
+        })
+    }
+}
+
+impl WritableNode for ControlNode {
+    fn encode(control: Control) -> Value {
+        Value::U8(control as u8)
+    }
+}
+
+impl TryFrom<u8> for Control {
+    type Error = u8;
+
+    fn try_from(control: u8) -> Result<Self, u8> {
+        use Control::*;
+
+        match control {
+            0 => Ok(Toggle),
+            1 => Ok(Play),
+            2 => Ok(Pause),
+            3 => Ok(Next),
+            4 => Ok(Previous),
+            _ => Err(control),
+        }
+    }
+}
+
+impl Radio {
+    /// Jumps to `position` in the current track
+    ///
+    /// Like shuffle, repeat and the rate this is not checked against the
+    /// capabilities, their bits are not verified. The radio refuses on its own.
+    pub async fn player_seek(&self, position: Duration) -> Result<(), Error> {
+        self.player.seek(position, &self.fsapi).await
+    }
+
+    pub async fn player_shuffle_set(&self, shuffle: bool) -> Result<(), Error> {
+        self.player.set_shuffle(shuffle, &self.fsapi).await
+    }
+
+    pub async fn player_repeat_set(&self, repeat: bool) -> Result<(), Error> {
+        self.player.set_repeat(repeat, &self.fsapi).await
+    }
+
+    /// Fast-forward with a positive `rate`, rewind with a negative one
+    ///
+    /// `1` is the normal speed.
+    pub async fn player_rate_set(&self, rate: i8) -> Result<(), Error> {
+        self.player.set_rate(rate, &self.fsapi).await
+    }
+
+    pub fn subscribe_shuffle(&self) -> watch::Receiver<bool> {
+        self.player.shuffle.subscribe()
+    }
+
+    pub fn subscribe_repeat(&self) -> watch::Receiver<bool> {
+        self.player.repeat.subscribe()
+    }
+
+    pub fn subscribe_rate(&self) -> watch::Receiver<i8> {
+        self.player.rate.subscribe()
+    }
+}
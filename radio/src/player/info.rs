@@ -1,68 +1,93 @@
-use std::fmt::Display;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
-use tokio::sync::Mutex;
+use tokio::sync::watch;
 
-use fsapi::{FsApi, Node, Value};
+use fsapi::node::{
+    PlayInfoAlbum, PlayInfoArtist, PlayInfoDuration, PlayInfoGraphicUri, PlayInfoName, PlayInfoText,
+};
+use fsapi::{FsApi, Node};
 
-use crate::Error;
+use crate::{take_typed, update_with, Error, Values};
 
 #[derive(Debug)]
 pub struct PlayerInfo {
+    pub(crate) now_playing: watch::Sender<NowPlaying>,
+}
+
+/// What the radio is playing right now
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NowPlaying {
     /// First line of display
-    pub(crate) name: Mutex<String>,
+    pub name: String,
 
     /// Second line of display
-    pub(crate) text: Mutex<String>,
+    pub text: String,
 
-    pub(crate) album: Mutex<String>,
-    pub(crate) artist: Mutex<String>,
+    pub album: String,
+    pub artist: String,
 
-    pub(crate) duration: Mutex<Duration>,
+    pub duration: Duration,
 
-    // TODO: Figure out when to reset since
-    // not all stations send it
-    pub(crate) graphic_uri: Mutex<String>,
+    /// Empty if there is no artwork, re-read on every
+    /// track or mode change since not all stations send it
+    pub graphic_uri: String,
 }
 
 impl PlayerInfo {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let name = match FsApi::get(Node::PlayInfoName, &host, pin).await? {
-            Value::Text(name) => name,
-            _ => unreachable!("PlayInfoName returns a Text"),
-        };
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
 
-        let text = match FsApi::get(Node::PlayInfoText, &host, pin).await? {
-            Value::Text(text) => text,
-            _ => unreachable!("PlayInfoText returns a Text"),
-        };
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        vec![
+            Node::PlayInfoName,
+            Node::PlayInfoText,
+            Node::PlayInfoAlbum,
+            Node::PlayInfoArtist,
+            Node::PlayInfoDuration,
+            Node::PlayInfoGraphicUri,
+        ]
+    }
 
-        let album = match FsApi::get(Node::PlayInfoAlbum, &host, pin).await? {
-            Value::Text(album) => album,
-            _ => unreachable!("PlayInfoAlbum returns a Text"),
-        };
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let name = take_typed::<PlayInfoName>(values)?;
 
-        let artist = match FsApi::get(Node::PlayInfoArtist, &host, pin).await? {
-            Value::Text(artist) => artist,
-            _ => unreachable!("PlayInfoArtist returns a Text"),
-        };
+        let text = take_typed::<PlayInfoText>(values)?;
 
-        let duration = match FsApi::get(Node::PlayInfoDuration, &host, pin).await? {
-            Value::U32(duration) => Duration::from_millis(duration.into()),
-            _ => unreachable!("PlayInfoDuration returns a U32"),
-        };
+        let album = take_typed::<PlayInfoAlbum>(values)?;
 
-        let graphic_uri = match FsApi::get(Node::PlayInfoGraphicUri, &host, pin).await? {
-            Value::Text(graphic_uri) => graphic_uri,
-            _ => unreachable!("PlayInfoGraphicUri returns a Text"),
+        let artist = take_typed::<PlayInfoArtist>(values)?;
+
+        let duration = take_typed::<PlayInfoDuration>(values)?;
+
+        let graphic_uri = take_typed::<PlayInfoGraphicUri>(values)?;
+
+        let now_playing = NowPlaying {
+            name,
+            text,
+            album,
+            artist,
+            duration,
+            graphic_uri,
         };
 
         Ok(Self {
-            name: Mutex::new(name),
-            text: Mutex::new(text),
-            album: Mutex::new(album),
-            artist: Mutex::new(artist),
-            duration: Mutex::new(duration),
-            graphic_uri: Mutex::new(graphic_uri),
+            now_playing: watch::channel(now_playing).0,
         })
     }
+
+    pub fn now_playing(&self) -> NowPlaying {
+        self.now_playing.borrow().clone()
+    }
+
+    /// Fetches the graphic uri, cleared when it can't be fetched
+    pub(crate) async fn refresh_graphic_uri(&self, fsapi: &FsApi) {
+        let graphic_uri = fsapi
+            .get_typed::<PlayInfoGraphicUri>()
+            .await
+            .unwrap_or_default();
+
+        update_with(&self.now_playing, |info| &mut info.graphic_uri, graphic_uri);
+    }
 }
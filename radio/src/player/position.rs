@@ -0,0 +1,221 @@
+use std::time::{Duration, Instant};
+
+use tokio::sync::watch;
+
+use super::{Player, Status};
+use crate::{update, Radio};
+
+/// The last position reported by the radio
+///
+/// Between two reports the position is interpolated,
+/// see [`Player::position`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub reported: Duration,
+
+    /// When `reported` was received
+    pub at: Instant,
+}
+
+impl Position {
+    pub(crate) fn new(reported: Duration) -> Self {
+        Self {
+            reported,
+            at: Instant::now(),
+        }
+    }
+
+    /// The position `elapsed` after the report while playing at `rate`,
+    /// at most `duration` unless that is zero
+    fn interpolate(&self, elapsed: Duration, rate: i8, duration: Duration) -> Duration {
+        let rate = match rate {
+            0 => 1,
+            rate => rate,
+        };
+        let elapsed = elapsed * rate.unsigned_abs().into();
+        let interpolated = if rate > 0 {
+            self.reported.saturating_add(elapsed)
+        } else {
+            self.reported.saturating_sub(elapsed)
+        };
+
+        match duration {
+            Duration::ZERO => interpolated,
+            duration => interpolated.min(duration),
+        }
+    }
+}
+
+impl Radio {
+    /// The interpolated position in the current track
+    ///
+    /// `None` for modes without tracks, like internet radio or fm.
+    pub fn player_position(&self) -> Option<Duration> {
+        self.mode().has_position().then(|| self.player.position())
+    }
+
+    /// How far the current track is played, from `0.0` to `1.0`
+    ///
+    /// `None` for modes without tracks or when the duration is unknown.
+    pub fn player_progress(&self) -> Option<f64> {
+        self.mode()
+            .has_position()
+            .then(|| self.player.progress())
+            .flatten()
+    }
+
+    pub fn subscribe_position(&self) -> watch::Receiver<Position> {
+        self.player.position.subscribe()
+    }
+}
+
+impl Player {
+    /// The position in the current track
+    ///
+    /// Interpolated from the last reported position while playing,
+    /// zero until the first report or for modes without a position.
+    pub fn position(&self) -> Duration {
+        let position = *self.position.borrow();
+        if self.status() != Status::Playing {
+            return position.reported;
+        }
+
+        let duration = self.info.now_playing.borrow().duration;
+        position.interpolate(position.at.elapsed(), self.rate(), duration)
+    }
+
+    /// See [`Radio::player_progress`]
+    pub fn progress(&self) -> Option<f64> {
+        let duration = self.info.now_playing.borrow().duration;
+        if duration.is_zero() {
+            return None;
+        }
+
+        Some(self.position().as_secs_f64() / duration.as_secs_f64())
+    }
+
+    /// A position reported by the radio
+    pub(crate) fn update_position(&self, position: Duration) -> bool {
+        update(&self.position, Position::new(position))
+    }
+
+    /// Starts interpolating from the current position,
+    /// before the status or rate changes
+    fn anchor_position(&self) {
+        self.update_position(self.position());
+    }
+
+    pub(crate) fn update_status(&self, status: Status) -> bool {
+        if status == self.status() {
+            return false;
+        }
+
+        self.anchor_position();
+        update(&self.status, status)
+    }
+
+    pub(crate) fn update_rate(&self, rate: i8) -> bool {
+        if rate == self.rate() {
+            return false;
+        }
+
+        self.anchor_position();
+        update(&self.rate, rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{ArtworkCache, PlayerCapabilities, PlayerInfo};
+    use super::*;
+    use crate::player::NowPlaying;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    fn reported(secs: u64) -> Position {
+        Position::new(Duration::from_secs(secs))
+    }
+
+    /// A player at `reported` two seconds ago
+    fn player(status: Status, rate: i8) -> Player {
+        let position = Position {
+            reported: SECOND * 10,
+            at: Instant::now() - SECOND * 2,
+        };
+
+        Player {
+            info: PlayerInfo {
+                now_playing: watch::channel(NowPlaying::default()).0,
+            },
+            status: watch::channel(status).0,
+            position: watch::channel(position).0,
+            signal_strength: watch::channel(0).0,
+            shuffle: watch::channel(false).0,
+            repeat: watch::channel(false).0,
+            rate: watch::channel(rate).0,
+            capabilities: watch::channel(PlayerCapabilities::ALL).0,
+            artwork: ArtworkCache::in_memory(),
+        }
+    }
+
+    #[test]
+    fn interpolates_at_the_rate() {
+        let position = reported(10);
+
+        assert_eq!(
+            position.interpolate(SECOND * 2, 1, Duration::ZERO),
+            SECOND * 12
+        );
+        assert_eq!(
+            position.interpolate(SECOND * 2, 0, Duration::ZERO),
+            SECOND * 12
+        );
+        assert_eq!(
+            position.interpolate(SECOND * 2, 4, Duration::ZERO),
+            SECOND * 18
+        );
+    }
+
+    #[test]
+    fn rewinds_with_a_negative_rate() {
+        let position = reported(10);
+
+        assert_eq!(
+            position.interpolate(SECOND * 2, -2, Duration::ZERO),
+            SECOND * 6
+        );
+        assert_eq!(
+            position.interpolate(SECOND * 20, -2, Duration::ZERO),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn stops_at_the_duration() {
+        let position = reported(10);
+
+        assert_eq!(
+            position.interpolate(SECOND * 5, 1, SECOND * 12),
+            SECOND * 12
+        );
+    }
+
+    #[test]
+    fn only_interpolates_while_playing() {
+        assert_eq!(player(Status::Paused, 1).position(), SECOND * 10);
+        assert!(player(Status::Playing, 1).position() >= SECOND * 12);
+    }
+
+    #[test]
+    fn status_and_rate_changes_keep_the_position() {
+        let paused = player(Status::Playing, 1);
+        paused.update_status(Status::Paused);
+        let position = paused.position();
+        assert!(position >= SECOND * 12 && position < SECOND * 13);
+
+        let fast = player(Status::Playing, 1);
+        fast.update_rate(8);
+        let position = fast.position();
+        assert!(position >= SECOND * 12 && position < SECOND * 13);
+    }
+}
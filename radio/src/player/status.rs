@@ -1,11 +1,12 @@
-use std::fmt::Display;
+use serde::{Deserialize, Serialize};
 
-use fsapi::{FsApi, Node};
+use fsapi::node::PlayStatus;
+use fsapi::{FsApi, TypedNode, Value};
 
-use super::Player;
+use super::{Player, PlayerCapabilities};
 use crate::{Error, Radio};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum Status {
     /// After switching between modes
@@ -28,37 +29,32 @@ pub enum Status {
 
 impl Radio {
     pub async fn player_toggle(&self) -> Result<(), Error> {
-        // TODO: Check `self.mode` or `Node::PlayCaps` to check
-        // if toggle is available for the current node.
-        // Currently if not available you get an Error::InvalidValue back
-        Player::toggle(&self.host, self.pin).await
+        self.player_require(PlayerCapabilities::PLAY_PAUSE, "toggle")?;
+        Player::toggle(&self.fsapi).await
     }
 
     pub async fn player_next(&self) -> Result<(), Error> {
-        // TODO: Check `self.mode` or `Node::PlayCaps` to check
-        // if next is available for the current node.
-        // Currently if not available you get an Error::InvalidValue back
-        Player::next(&self.host, self.pin).await
+        self.player_require(PlayerCapabilities::SKIP, "next")?;
+        Player::next(&self.fsapi).await
     }
 
     pub async fn player_prev(&self) -> Result<(), Error> {
-        // TODO: Check `self.mode` or `Node::PlayCaps` to check
-        // if next is available for the current node.
-        // Currently if not available you get an Error::InvalidValue back
-        Player::next(&self.host, self.pin).await
+        self.player_require(PlayerCapabilities::SKIP, "previous")?;
+        Player::prev(&self.fsapi).await
     }
 
     pub async fn player_get_status(&self) -> Result<Status, Error> {
-        Status::get(&self.host, self.pin).await
+        Status::get(&self.fsapi).await
     }
 }
 
 impl Status {
-    pub async fn get<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        Ok(match FsApi::get(Node::PlayControl, host, pin).await? {
-            fsapi::Value::U8(status) => Status::from(status),
-            _ => unreachable!("SysPlayControl returns a U8"),
-        })
+    pub async fn get(fsapi: &FsApi) -> Result<Self, Error> {
+        Ok(fsapi.get_typed::<PlayStatus>().await?.into())
+    }
+
+    pub(crate) fn from_value(value: Value) -> Result<Self, Error> {
+        Ok(PlayStatus::decode(value)?.into())
     }
 }
 
@@ -1,55 +1,63 @@
-use std::fmt::Display;
-use tokio::sync::Mutex;
+use tokio::sync::watch;
 
+use fsapi::node::SysPower;
 use fsapi::{FsApi, Node};
 
-use crate::{Error, Radio};
+use crate::{take_typed, update, Error, Radio, Values};
 
 #[derive(Debug)]
 pub struct Power {
-    pub(crate) state: Mutex<bool>,
+    pub(crate) state: watch::Sender<bool>,
 }
 
 impl Radio {
     pub async fn power_set(&self, power: bool) -> Result<(), Error> {
-        self.power.set(power, &self.host, self.pin).await
+        self.power.set(power, &self.fsapi).await
+    }
+
+    pub fn subscribe_power(&self) -> watch::Receiver<bool> {
+        self.power.state.subscribe()
     }
 }
 
 impl Power {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let state = match FsApi::get(Node::SysPower, &host, pin).await? {
-            fsapi::Value::U8(state) => state == 1,
-            _ => unreachable!("Power returns a U8"),
-        };
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
+
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        vec![Node::SysPower]
+    }
+
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let state = take_typed::<SysPower>(values)?;
 
         Ok(Self {
-            state: Mutex::new(state),
+            state: watch::channel(state).0,
         })
     }
 
-    pub async fn set<D: Display>(&self, state: bool, host: D, pin: u32) -> Result<(), Error> {
-        let lock = self.state.lock().await;
-        let old_state = *lock;
-        drop(lock);
+    pub fn state(&self) -> bool {
+        *self.state.borrow()
+    }
 
-        if state != old_state {
-            FsApi::set(Node::SysPower, if old_state { 1 } else { 0 }, host, pin).await?;
+    pub async fn set(&self, state: bool, fsapi: &FsApi) -> Result<(), Error> {
+        if state != self.state() {
+            fsapi.set_typed::<SysPower>(state).await?;
         };
 
-        *self.state.lock().await = state;
+        update(&self.state, state);
 
         Ok(())
     }
 
-    pub async fn toggle<D: Display>(&self, host: D, pin: u32) -> Result<bool, Error> {
-        let lock = self.state.lock().await;
-        let new_state = !*lock;
-        drop(lock);
+    pub async fn toggle(&self, fsapi: &FsApi) -> Result<bool, Error> {
+        let new_state = !self.state();
 
-        FsApi::set(Node::SysPower, if new_state { 1 } else { 0 }, host, pin).await?;
+        fsapi.set_typed::<SysPower>(new_state).await?;
 
-        *self.state.lock().await = new_state;
+        update(&self.state, new_state);
 
         Ok(new_state)
     }
@@ -1,47 +1,55 @@
-use std::fmt::Display;
 use std::time::Duration;
-use tokio::sync::Mutex;
+use tokio::sync::watch;
 
+use fsapi::node::SysSleep;
 use fsapi::{FsApi, Node};
 
-use crate::{Error, Radio};
+use crate::{take_typed, update, Error, Radio, Values};
 
 #[derive(Debug)]
 pub struct Sleep {
     // TODO: replace with DateTime
     /// 0 is disabled
-    pub(crate) sleep_in: Mutex<Duration>,
+    pub(crate) sleep_in: watch::Sender<Duration>,
 }
 
 impl Radio {
     pub async fn sleep_in(&self, sleep_in: Duration) -> Result<(), Error> {
-        FsApi::set(Node::SysSleep, sleep_in.as_secs(), &self.host, self.pin).await?;
+        self.sleep.set(sleep_in, &self.fsapi).await
+    }
 
-        Ok(())
+    pub fn subscribe_sleep(&self) -> watch::Receiver<Duration> {
+        self.sleep.sleep_in.subscribe()
     }
 }
 
 impl Sleep {
-    pub async fn new<D: Display>(host: D, pin: u32) -> Result<Self, Error> {
-        let sleep_in = match FsApi::get(Node::SysSleep, &host, pin).await? {
-            fsapi::Value::U32(duration) => Duration::from_secs(duration.into()),
-            _ => unreachable!("Power returns a U32"),
-        };
+    pub async fn new(fsapi: &FsApi) -> Result<Self, Error> {
+        Self::from_values(&mut fsapi.get_multiple(Self::nodes()).await?)
+    }
+
+    /// Nodes needed by [`Self::from_values`]
+    pub(crate) fn nodes() -> Vec<Node> {
+        vec![Node::SysSleep]
+    }
+
+    pub(crate) fn from_values(values: &mut Values) -> Result<Self, Error> {
+        let sleep_in = take_typed::<SysSleep>(values)?;
 
         Ok(Self {
-            sleep_in: Mutex::new(sleep_in),
+            sleep_in: watch::channel(sleep_in).0,
         })
     }
 
-    pub async fn set<D: Display>(
-        &mut self,
-        sleep_in: Duration,
-        host: D,
-        pin: u32,
-    ) -> Result<(), Error> {
-        FsApi::set(Node::SysSleep, sleep_in.as_secs(), host, pin).await?;
+    /// Time until the radio goes to sleep, zero if disabled
+    pub fn sleep_in(&self) -> Duration {
+        *self.sleep_in.borrow()
+    }
+
+    pub async fn set(&self, sleep_in: Duration, fsapi: &FsApi) -> Result<(), Error> {
+        fsapi.set_typed::<SysSleep>(sleep_in).await?;
 
-        *self.sleep_in.lock().await = sleep_in;
+        update(&self.sleep_in, sleep_in);
 
         Ok(())
     }
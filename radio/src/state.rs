@@ -0,0 +1,79 @@
+use std::sync::PoisonError;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::audio::eq::EqPreset;
+use crate::mode::Mode;
+use crate::player::{NowPlaying, Status};
+use crate::Radio;
+
+/// Everything known about the radio at one point in time
+///
+/// Taken with [`Radio::snapshot`], it does not change when the radio does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RadioState {
+    pub power: bool,
+    pub mode: Mode,
+    pub volume: u32,
+    pub muted: bool,
+    pub eq: EqState,
+
+    /// Time until the radio goes to sleep, zero if disabled
+    pub sleep_in: Duration,
+
+    pub now_playing: NowPlaying,
+    pub play_status: Status,
+    pub position: Duration,
+    pub signal_strength: u8,
+    pub shuffle: bool,
+    pub repeat: bool,
+    pub rate: i8,
+}
+
+/// Equalizer settings of a [`RadioState`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EqState {
+    pub preset: EqPreset,
+
+    /// Only used by [`EqPreset::Custom`]
+    pub loudness: bool,
+    pub bass: i32,
+    pub trebble: i32,
+}
+
+impl Radio {
+    /// A copy of the current state
+    ///
+    /// Events handled by [`Radio::handle_event`] are either
+    /// completely in the snapshot or not at all.
+    pub fn snapshot(&self) -> RadioState {
+        let _update = self
+            .update_lock
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let eq = &self.audio.eq;
+
+        RadioState {
+            power: self.power.state(),
+            mode: self.mode(),
+            volume: self.audio.volume.volume(),
+            muted: self.audio.volume.muted(),
+            eq: EqState {
+                preset: eq.preset(),
+                loudness: eq.custom.loudness(),
+                bass: eq.custom.bass(),
+                trebble: eq.custom.trebble(),
+            },
+            sleep_in: self.sleep.sleep_in(),
+            now_playing: self.player.info.now_playing(),
+            play_status: self.player.status(),
+            position: self.player.position(),
+            signal_strength: self.player.signal_strength(),
+            shuffle: self.player.shuffle(),
+            repeat: self.player.repeat(),
+            rate: self.player.rate(),
+        }
+    }
+}
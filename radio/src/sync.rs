@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use fsapi::{Error as FsApiError, Node, Notification};
+use futures_util::StreamExt;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+use crate::{Error, Radio, RadioEvent};
+
+/// How often [`Radio::spawn_sync`] re-fetches the nodes without notifications
+///
+/// The position is interpolated in between, see [`Player::position`](crate::player::Player::position).
+pub const SYNC_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Nodes that change without the radio sending a notification
+const POLLED_NODES: [Node; 3] = [Node::PlayPosition, Node::PlaySignalStrength, Node::SysSleep];
+
+/// Handle of the task started by [`Radio::spawn_sync`]
+///
+/// Dropping the handle stops the task as well,
+/// but without waiting for the session to be deleted.
+#[derive(Debug)]
+pub struct SyncHandle {
+    stop: oneshot::Sender<()>,
+    task: JoinHandle<Result<(), Error>>,
+}
+
+impl SyncHandle {
+    /// Whether the task stopped on its own, because of an error
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Stops the task and deletes the session
+    ///
+    /// Returns the error the task stopped with, if any,
+    /// else the error deleting the session failed with.
+    /// The session can't be used again, so anything that needs it fails
+    /// afterwards, like [`Radio::nav_list`] or [`Radio::presets_refresh`].
+    pub async fn stop(self) -> Result<(), Error> {
+        // Fails if the task already stopped, the result tells why
+        let _ = self.stop.send(());
+
+        self.task.await?
+    }
+}
+
+impl Radio {
+    /// Keeps the state of the radio up to date in the background
+    ///
+    /// Applies every notification with [`Radio::handle_event`] and
+    /// re-fetches the nodes that don't send notifications every [`SYNC_INTERVAL`].
+    /// Use the `subscribe_*` methods or [`Radio::snapshot`] to follow the state.
+    ///
+    /// The session is deleted when the task stops, see [`SyncHandle::stop`].
+    pub fn spawn_sync(self: &Arc<Self>) -> SyncHandle {
+        self.spawn_sync_every(SYNC_INTERVAL)
+    }
+
+    /// Like [`Radio::spawn_sync`], re-fetching every `interval`
+    pub fn spawn_sync_every(self: &Arc<Self>, interval: Duration) -> SyncHandle {
+        let (stop, stopped) = oneshot::channel();
+        let task = tokio::spawn(self.clone().sync(interval, stopped));
+
+        SyncHandle { stop, task }
+    }
+
+    async fn sync(
+        self: Arc<Self>,
+        interval: Duration,
+        mut stopped: oneshot::Receiver<()>,
+    ) -> Result<(), Error> {
+        let mut events = self.events();
+        let mut poll = tokio::time::interval(interval);
+        poll.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let result = loop {
+            let handled = tokio::select! {
+                _ = &mut stopped => break Ok(()),
+                event = events.next() => match event {
+                    Some(Ok(event)) => self.handle_event(&event).await,
+                    Some(Err(err)) => break Err(err),
+                    None => break Ok(()),
+                },
+                _ = poll.tick() => self.poll().await,
+            };
+
+            if let Err(err) = handled {
+                break Err(err);
+            }
+        };
+
+        // Drop the stream first, it holds on to the session
+        drop(events);
+        let shutdown = self.shutdown().await;
+
+        // Why the loop stopped matters more than a failed shutdown
+        result.and(shutdown)
+    }
+
+    /// Re-fetches the [`POLLED_NODES`]
+    ///
+    /// Nodes that are not available in the current mode are skipped,
+    /// network errors and garbled responses are left for the next poll.
+    async fn poll(&self) -> Result<(), Error> {
+        let values = match self.fsapi.get_multiple(POLLED_NODES).await {
+            Ok(values) => values,
+            Err(FsApiError::Http { .. } | FsApiError::InvalidResponse { .. }) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        for (node, value) in values {
+            let Ok(value) = value else {
+                continue;
+            };
+
+            let event = RadioEvent::from(Notification { node, value });
+            self.handle_event(&event).await?;
+        }
+
+        Ok(())
+    }
+}
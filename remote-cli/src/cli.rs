@@ -1,8 +1,11 @@
-use std::fmt::Display;
+use std::path::PathBuf;
 
 use clap::{Args, Parser, Subcommand};
 
-use fsapi::{FsApi, Node, Value};
+use fsapi::node::{NavActionSelectPreset, NavState, SysAudioMute, SysAudioVolume, SysPower};
+use fsapi::{FsApi, Node};
+use radio::backup::PresetBackup;
+use radio::Radio;
 
 use crate::error::{Error, Result};
 
@@ -36,6 +39,21 @@ pub enum Command {
 
     #[clap(visible_aliases = &["f"])]
     Favorite(Number),
+
+    /// Back up or restore the presets
+    #[clap(subcommand)]
+    Presets(Presets),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Presets {
+    /// Save the presets of every mode to a TOML or JSON file
+    ///
+    /// Every preset is played once to read its station.
+    Backup { file: PathBuf },
+
+    /// Store the presets of a backup file on the radio
+    Restore { file: PathBuf },
 }
 
 #[derive(Debug, Subcommand)]
@@ -65,74 +83,102 @@ impl Default for Cli {
 }
 
 impl Command {
-    pub async fn execute<D: Display + Copy>(&self, host: D, pin: u32) -> Result<()> {
+    pub async fn execute(&self, fsapi: &FsApi) -> Result<()> {
         match self {
-            Command::On => power(true, host, pin).await?,
-            Command::Off => power(false, host, pin).await?,
-            Command::Mute => mute(true, host, pin).await?,
-            Command::UnMute => mute(false, host, pin).await?,
+            Command::On => power(true, fsapi).await?,
+            Command::Off => power(false, fsapi).await?,
+            Command::Mute => mute(true, fsapi).await?,
+            Command::UnMute => mute(false, fsapi).await?,
             Command::Volume(command) => match command {
                 Volume::Set(Number { number }) => {
-                    FsApi::set(
-                        Node::SysAudioVolume,
-                        number.ok_or(Error::InvalidCommand)?,
-                        host,
-                        pin,
-                    )
-                    .await?
+                    fsapi
+                        .set(Node::SysAudioVolume, number.ok_or(Error::InvalidCommand)?)
+                        .await?
                 }
                 Volume::Up(Number { number }) => {
-                    FsApi::set(
-                        Node::SysAudioVolume,
-                        get_volume(host, pin)
-                            .await?
-                            .checked_add(number.unwrap_or(1))
-                            .ok_or(Error::InvalidCommand)?,
-                        host,
-                        pin,
-                    )
-                    .await?
+                    fsapi
+                        .set(
+                            Node::SysAudioVolume,
+                            get_volume(fsapi)
+                                .await?
+                                .checked_add(number.unwrap_or(1))
+                                .ok_or(Error::InvalidCommand)?,
+                        )
+                        .await?
                 }
                 Volume::Down(Number { number }) => {
-                    FsApi::set(
-                        Node::SysAudioVolume,
-                        get_volume(host, pin)
-                            .await?
-                            .checked_sub(number.unwrap_or(1))
-                            .ok_or(Error::InvalidCommand)?,
-                        host,
-                        pin,
-                    )
-                    .await?
+                    fsapi
+                        .set(
+                            Node::SysAudioVolume,
+                            get_volume(fsapi)
+                                .await?
+                                .checked_sub(number.unwrap_or(1))
+                                .ok_or(Error::InvalidCommand)?,
+                        )
+                        .await?
                 }
             },
             Command::Favorite(Number { number }) => {
-                FsApi::set(Node::NavState, 1, &host, pin).await?;
-                FsApi::set(
-                    Node::NavActionSelectPreset,
-                    number.ok_or(Error::InvalidCommand)?,
-                    &host,
-                    pin,
-                )
-                .await?
+                fsapi.set_typed::<NavState>(true).await?;
+                fsapi
+                    .set_typed::<NavActionSelectPreset>(number.ok_or(Error::InvalidCommand)?)
+                    .await?
             }
+            Command::Presets(command) => presets(command, fsapi).await?,
         }
 
         Ok(())
     }
 }
 
-async fn get_volume<D: Display>(host: D, pin: u32) -> Result<u32> {
-    Ok(match FsApi::get(Node::SysAudioVolume, &host, pin).await? {
-        Value::U8(volume) => volume as u32,
-        _ => unreachable!("SysCapsVolume returns a U8"),
-    })
+async fn get_volume(fsapi: &FsApi) -> Result<u32> {
+    Ok(fsapi.get_typed::<SysAudioVolume>().await?.into())
+}
+
+async fn mute(mute: bool, fsapi: &FsApi) -> Result<()> {
+    Ok(fsapi.set_typed::<SysAudioMute>(mute).await?)
 }
 
-async fn mute<D: Display>(mute: bool, host: D, pin: u32) -> Result<()> {
-    Ok(FsApi::set(Node::SysAudioMute, if mute { 1 } else { 0 }, host, pin).await?)
+async fn power(on: bool, fsapi: &FsApi) -> Result<()> {
+    Ok(fsapi.set_typed::<SysPower>(on).await?)
 }
 
-async fn power<D: Display>(on: bool, host: D, pin: u32) -> Result<()> {
-    Ok(FsApi::set(Node::SysPower, if on { 1 } else { 0 }, host, pin).await?)
+async fn presets(command: &Presets, fsapi: &FsApi) -> Result<()> {
+    let radio = Radio::with_client(fsapi.clone())
+        .await
+        .map_err(|err| Error::Radio(err.to_string()))?;
+
+    match command {
+        Presets::Backup { file } => {
+            let report = radio.backup_presets().await;
+            for failure in &report.failures {
+                eprintln!("Could not back up {}: {}", failure.mode, failure.error);
+            }
+            if let Some(err) = &report.switch_back {
+                eprintln!("Could not switch back: {err}");
+            }
+            report.backup.save(file)?;
+            println!("Saved {} presets", report.backup.presets.len());
+        }
+        Presets::Restore { file } => {
+            let backup = PresetBackup::load(file)?;
+            let report = radio.restore_presets(&backup).await;
+            for failure in &report.failures {
+                let preset = &failure.preset;
+                eprintln!(
+                    "Could not restore {} ({} {}): {}",
+                    preset.name, preset.mode, preset.slot, failure.error
+                );
+            }
+            if let Some(err) = &report.switch_back {
+                eprintln!("Could not switch back: {err}");
+            }
+            let restored = backup.presets.len() - report.failures.len();
+            println!("Restored {restored} presets");
+        }
+    }
+
+    radio.shutdown().await?;
+
+    Ok(())
 }
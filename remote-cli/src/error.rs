@@ -1,10 +1,13 @@
 use fsapi::Error as FsApiError;
+use radio::Error as RadioError;
+
 #[derive(Debug)]
 pub enum Error {
     NoConfig(String),
     InvalidConfig(String),
     InvalidPin,
     InvalidCommand,
+    Radio(String),
     Internal,
 }
 
@@ -18,6 +21,7 @@ impl std::fmt::Display for Error {
             Self::InvalidPin => write!(f, "Provided pin is invalid"),
             Self::Internal => write!(f, "Internal error"),
             Self::InvalidCommand => write!(f, "Invalid command provided"),
+            Self::Radio(msg) => write!(f, "{msg}"),
         }
     }
 }
@@ -27,8 +31,17 @@ impl std::error::Error for Error {}
 impl From<FsApiError> for Error {
     fn from(err: FsApiError) -> Self {
         match err {
-            FsApiError::WrongPin => Self::InvalidPin,
+            FsApiError::WrongPin(_) => Self::InvalidPin,
             _ => Self::Internal,
         }
     }
 }
+
+impl From<RadioError> for Error {
+    fn from(err: RadioError) -> Self {
+        match err {
+            RadioError::Auth => Self::InvalidPin,
+            err => Self::Radio(err.to_string()),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use cli::Cli;
 use config::read_config;
 use error::Result;
+use fsapi::FsApi;
 
 mod cli;
 mod config;
@@ -10,10 +11,10 @@ mod error;
 async fn main() -> Result<()> {
     let config = read_config()?;
 
+    let fsapi = FsApi::new(&config.connection.host, config.connection.pin)?;
+
     let args = Cli::default();
-    args.command
-        .execute(&config.connection.host, config.connection.pin)
-        .await?;
+    args.command.execute(&fsapi).await?;
 
     Ok(())
 }
@@ -1,7 +1,8 @@
 use dotenv::dotenv;
+use futures_util::StreamExt;
 use std::env;
 
-use fsapi::FsApi;
+use fsapi::{FsApi, SessionManager};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -10,13 +11,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let host = env::var("HOST")?;
     let pin = env::var("PIN")?.parse()?;
 
-    let session_id = FsApi::create_session(&host, pin).await?;
+    let fsapi = FsApi::new(host, pin)?;
+    let session = SessionManager::new(fsapi).await?;
 
-    loop {
-        if let Some(notifications) = FsApi::get_notifications(session_id, &host, pin).await? {
-            for notification in notifications {
-                println!("{}: {}", notification.node, notification.value);
-            }
-        }
+    let mut notifications = session.notifications();
+
+    while let Some(notification) = notifications.next().await {
+        let notification = notification?;
+        println!("{}: {}", notification.node, notification.value);
     }
+
+    Ok(())
 }